@@ -1,5 +1,6 @@
-use crate::{prelude::*, value::optics as value_optics};
-use std::sync::Arc;
+use crate::{prelude::*, read2::AnomalyMap, value::optics as value_optics};
+use ::core::fmt;
+use std::sync::{Arc, OnceLock, Weak};
 
 /// Evaluates a value in the given environment and context.
 ///
@@ -21,12 +22,25 @@ use std::sync::Arc;
 ///
 /// The evaluated value as a `PtrValue`
 ///
+/// # Panics
+///
+/// Panics with the error's message if evaluation fails; see [`try_eval`] for a fallible version.
+///
 /// # Special Forms
 ///
 /// Lists starting with certain symbols are treated as special forms:
+/// - `if` - Conditional evaluation (only `nil` and `false` are falsey)
+/// - `def` - Var creation in the current namespace
+/// - `quote` - Returns its argument unevaluated
+/// - `var` - Returns the Var a symbol resolves to, without dereferencing it
 /// - `let*` - Sequential binding with context threading
+/// - `loop*` - Like `let*`, but also a target for `recur`
+/// - `recur` - Rebinds the enclosing `loop*` or `fn*` and re-runs it (tail position only)
 /// - `fn*` - Function definition
+/// - `letfn*` - Binding of mutually recursive local functions
 /// - `do` - Sequential expression evaluation
+/// - `throw` - Raises an `ex-info` map as an [`EvalError`]
+/// - `try` - Evaluates a body, handling errors with `catch` and cleaning up with `finally`
 ///
 /// # Example
 ///
@@ -42,79 +56,170 @@ use std::sync::Arc;
 /// assert_eq!(result, Value::integer_ptr(42));
 /// ```
 pub fn eval(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> PtrValue {
+    try_eval(env, ctx, v).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Returns whether `name` is the name of a special form (see [`eval`]).
+///
+/// Syntax-quote leaves these symbols unqualified.
+pub fn is_special_form(name: &str) -> bool {
+    matches!(
+        name,
+        "if" | "def"
+            | "quote"
+            | "var"
+            | "let*"
+            | "loop*"
+            | "recur"
+            | "fn*"
+            | "letfn*"
+            | "do"
+            | "throw"
+            | "try"
+    )
+}
+
+/// The name of `value` if it is a symbol, for recognizing the symbols with special meaning inside
+/// a special form: `&` in parameters and binding patterns, and `catch` and `finally` in `try`.
+/// These are ordinary symbols anywhere else. Syntax-quote qualifies them with the current
+/// namespace, so a qualified symbol with the same name counts too.
+fn marker_symbol_name(value: &Value) -> Option<&str> {
+    match value {
+        Value::Symbol(symbol, _) => Some(symbol.name()),
+        _ => None,
+    }
+}
+
+/// Evaluates a value in the given environment and context, returning an [`EvalError`] instead of
+/// panicking when the form is malformed, a symbol can't be resolved, or a function is applied to
+/// the wrong number of arguments.
+///
+/// Builtin functions that panic still unwind through this function.
+///
+/// # Example
+///
+/// ```
+/// # use jinme::prelude::*;
+/// # use std::sync::Arc;
+/// let mut builder = Environment::builder();
+/// builder.insert_namespace(Arc::new(Namespace::new_empty("clojure.core")));
+/// builder.set_current_namespace_var("clojure.core", "*ns*");
+/// let env = builder.build_ptr();
+/// let ctx = EvalContext::new_empty();
+/// let err = try_eval(env, ctx, Value::symbol_unqualified_ptr("no-such-var")).unwrap_err();
+/// assert_eq!(err.category(), &KeywordUnqualified::new("not-found"));
+/// ```
+pub fn try_eval(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> Result<PtrValue, EvalError> {
+    try_eval_form(env, ctx, v.clone()).map_err(|err| err.or_position_of(&v))
+}
+
+fn try_eval_form(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    v: PtrValue,
+) -> Result<PtrValue, EvalError> {
     match v.as_ref() {
-        Value::Nil(_) => v,
+        Value::Nil(_) => Ok(v),
         Value::Symbol(symbol, _) => {
             // Check local bindings first (from let*, fn* parameters, etc.)
             if let Symbol::Unqualified(sym_unq) = symbol {
                 if let Some(local_value) = ctx.resolve_local(sym_unq.name()) {
-                    return local_value;
+                    return Ok(local_value);
                 }
             }
             // Fall back to namespace resolution
-            resolve_or_panic(env.clone(), symbol)
+            try_resolve(env.clone(), symbol)
+                .map_err(|err| EvalError::not_found(v.clone(), err.to_string()))?
                 .deref()
-                .expect(&format!("attempted to deref unbound Var: {}", symbol))
+                .ok_or_else(|| {
+                    EvalError::not_found(
+                        v.clone(),
+                        format!("attempted to deref unbound Var: {}", symbol),
+                    )
+                })
         }
-        Value::Keyword(_, _) => v,
-        Value::Boolean(_, _) => v,
-        Value::Integer(_, _) => v,
-        Value::Float(_, _) => v,
-        Value::String(_, _) => v,
+        Value::Keyword(_, _) => Ok(v),
+        Value::Boolean(_, _) => Ok(v),
+        Value::Integer(_, _) => Ok(v),
+        Value::Float(_, _) => Ok(v),
+        Value::String(_, _) => Ok(v),
+        Value::Char(_, _) => Ok(v),
+        Value::Tagged(_, _) => Ok(v),
         Value::List(list, _) => {
             if list.is_empty() {
-                return v;
+                return Ok(v);
             }
 
             // Check for special forms before evaluating arguments
             if let Some(head) = list.get_first() {
                 if let Value::Symbol(Symbol::Unqualified(head_sym), _) = head.as_ref() {
                     match head_sym.name() {
-                        "let*" => return eval_let_star(env.clone(), ctx.clone(), list),
+                        "if" => return eval_if(env.clone(), ctx.clone(), list)?.into_value(&v),
+                        "def" => return eval_def(env.clone(), ctx.clone(), list),
+                        "quote" => return eval_quote(list),
+                        "var" => return eval_var(env.clone(), list),
+                        "let*" => {
+                            return eval_let_star(env.clone(), ctx.clone(), list)?.into_value(&v);
+                        }
+                        "loop*" => return eval_loop_star(env.clone(), ctx.clone(), list),
+                        "recur" => {
+                            return eval_recur(env.clone(), ctx.clone(), list)?.into_value(&v);
+                        }
                         "fn*" => return eval_fn_star(env.clone(), ctx.clone(), list),
-                        "do" => return eval_do(env.clone(), ctx.clone(), list),
+                        "letfn*" => {
+                            return eval_letfn_star(env.clone(), ctx.clone(), list)?.into_value(&v);
+                        }
+                        "do" => return eval_do(env.clone(), ctx.clone(), list)?.into_value(&v),
+                        "throw" => return eval_throw(env.clone(), ctx.clone(), list),
+                        "try" => return eval_try(env.clone(), ctx.clone(), list),
                         _ => {}
                     }
                 }
             }
 
+            if let Some(expansion) = try_expand_macro_call(env.clone(), ctx.clone(), &v)? {
+                return try_eval(env, ctx, expansion);
+            }
+
             // Regular function application
-            let args: Vec<PtrValue> = list
+            let args = list
                 .iter()
                 .skip(1)
-                .map(|value| eval(env.clone(), ctx.clone(), value.to_owned()))
-                .collect();
-            let v = eval(
+                .map(|value| try_eval(env.clone(), ctx.clone(), value.to_owned()))
+                .collect::<Result<Vec<PtrValue>, EvalError>>()?;
+            let f = try_eval(
                 env.clone(),
                 ctx.clone(),
                 list.get_first().unwrap().to_owned(),
-            );
-            apply(env.clone(), ctx.clone(), v, args)
+            )?;
+            try_apply(env.clone(), ctx.clone(), f, args)
         }
-        Value::Vector(vector, _) => Value::new_vector_ptr(
+        Value::Vector(vector, _) => Ok(Value::new_vector_ptr(
             vector
                 .iter()
-                .map(|value| eval(env.clone(), ctx.clone(), value.to_owned()))
-                .collect(),
-        ),
-        Value::Set(set, _) => Value::new_set_ptr(
+                .map(|value| try_eval(env.clone(), ctx.clone(), value.to_owned()))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::Set(set, _) => Ok(Value::new_set_ptr(
             set.iter()
-                .map(|value| eval(env.clone(), ctx.clone(), value.to_owned()))
-                .collect(),
-        ),
-        Value::Map(map, _) => Value::new_map_ptr(
+                .map(|value| try_eval(env.clone(), ctx.clone(), value.to_owned()))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::Map(map, _) => Ok(Value::new_map_ptr(
             map.iter()
                 .map(|(k, v)| {
-                    (
-                        eval(env.clone(), ctx.clone(), k.to_owned()),
-                        eval(env.clone(), ctx.clone(), v.to_owned()),
-                    )
+                    Ok((
+                        try_eval(env.clone(), ctx.clone(), k.to_owned())?,
+                        try_eval(env.clone(), ctx.clone(), v.to_owned())?,
+                    ))
                 })
-                .collect(),
-        ),
-        Value::Var(var, _) => var.deref().expect("attempted to deref unbound Var"),
-        Value::Function(_, _) => v,
-        Value::Handle(_, _) => v,
+                .collect::<Result<_, EvalError>>()?,
+        )),
+        Value::Var(var, _) => var.deref().ok_or_else(|| {
+            EvalError::not_found(v.clone(), "attempted to deref unbound Var".to_owned())
+        }),
+        Value::Function(_, _) => Ok(v),
+        Value::Handle(_, _) => Ok(v),
     }
 }
 
@@ -123,7 +228,6 @@ pub fn eval(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> PtrValue {
 /// This function handles function invocation for different function types:
 /// - `Function` values invoke their bodies with the provided arguments
 /// - `Handle` values are downcast to `Function` if possible
-/// - Other types return the function value unchanged (with a warning)
 ///
 /// # Arguments
 ///
@@ -136,6 +240,11 @@ pub fn eval(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> PtrValue {
 ///
 /// The result of the function application
 ///
+/// # Panics
+///
+/// Panics with the error's message if application fails; see [`try_apply`] for a fallible
+/// version.
+///
 /// # Example
 ///
 /// ```
@@ -158,301 +267,1202 @@ pub fn eval(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> PtrValue {
 /// assert_eq!(result, Value::integer_ptr(3));
 /// ```
 pub fn apply(env: PtrEnvironment, ctx: EvalContext, f: PtrValue, args: Vec<PtrValue>) -> PtrValue {
+    try_apply(env, ctx, f, args).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Applies a function to a list of arguments, returning an [`EvalError`] instead of panicking
+/// when `f` is not a function or doesn't support the number of arguments given.
+pub fn try_apply(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    f: PtrValue,
+    args: Vec<PtrValue>,
+) -> Result<PtrValue, EvalError> {
     match f.as_ref() {
-        Value::Function(func, _) => func.invoke(env.clone(), ctx.clone(), args),
+        Value::Function(func, _) => func.try_invoke(env.clone(), ctx.clone(), args),
         Value::Handle(handle, _) => {
             if let Some(func) = handle.downcast_ref::<Function>() {
-                func.invoke(env.clone(), ctx.clone(), args)
+                func.try_invoke(env.clone(), ctx.clone(), args)
             } else {
-                f
+                Err(EvalError::unsupported(
+                    f.clone(),
+                    format!("cannot apply non-function value: {}", f),
+                ))
             }
         }
-        // TODO: properly handle other variants
-        _ => {
-            eprintln!("Warning: apply called on non-function value: {:?}", f);
-            f
+        _ => Err(EvalError::unsupported(
+            f.clone(),
+            format!("cannot apply non-function value: {}", f),
+        )),
+    }
+}
+
+/// The outcome of evaluating a form in tail position.
+///
+/// `recur` doesn't produce a value: it is handed back up to the enclosing `loop*` or `fn*`, which
+/// rebinds and re-runs its body in a Rust loop, so iteration runs in constant stack space.
+enum Tail {
+    Value(PtrValue),
+    Recur(Vec<PtrValue>),
+}
+
+impl Tail {
+    /// Unwraps the value of `form` evaluated outside of tail position, where `recur` is an error.
+    fn into_value(self, form: &PtrValue) -> Result<PtrValue, EvalError> {
+        match self {
+            Tail::Value(value) => Ok(value),
+            Tail::Recur(_) => Err(EvalError::incorrect(
+                form.clone(),
+                "recur: can only be used in tail position of loop* or fn*".to_owned(),
+            )),
         }
     }
 }
 
-/// Special form: do
-/// (do expr1 expr2 ... exprN) -> evaluate each in order, return value of exprN (or nil if empty)
-fn eval_do(env: PtrEnvironment, ctx: EvalContext, list: &List) -> PtrValue {
-    let body_exprs: Vec<PtrValue> = list.iter().skip(1).map(|v| v.to_owned()).collect();
+/// Evaluates a form in tail position: like [`try_eval`], except that `if`, `do`, `let*` and `letfn*`
+/// propagate tail position to their last expression and `recur` is allowed. Macro calls are
+/// expanded first, so their expansions are in tail position too.
+fn eval_tail(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> Result<Tail, EvalError> {
+    eval_tail_form(env, ctx, v.clone()).map_err(|err| err.or_position_of(&v))
+}
 
-    if body_exprs.is_empty() {
-        return Value::nil_ptr();
+fn eval_tail_form(env: PtrEnvironment, ctx: EvalContext, v: PtrValue) -> Result<Tail, EvalError> {
+    if let Value::List(list, _) = v.as_ref() {
+        if let Some(Value::Symbol(Symbol::Unqualified(head_sym), _)) = list.get_first().as_deref() {
+            match head_sym.name() {
+                "if" => return eval_if(env, ctx, list),
+                "do" => return eval_do(env, ctx, list),
+                "let*" => return eval_let_star(env, ctx, list),
+                "letfn*" => return eval_letfn_star(env, ctx, list),
+                "recur" => return eval_recur(env, ctx, list),
+                _ => {}
+            }
+        }
+        if let Some(expansion) = try_expand_macro_call(env.clone(), ctx.clone(), &v)? {
+            return eval_tail(env, ctx, expansion);
+        }
     }
+    try_eval(env, ctx, v).map(Tail::Value)
+}
 
-    let mut result = Value::nil_ptr();
-    for expr in body_exprs {
-        result = eval(env.clone(), ctx.clone(), expr);
+/// Expands `form` once if it is a macro call, i.e. a list whose head is a symbol naming a Var
+/// with a truthy `:macro` in its metadata. Returns `None` otherwise.
+///
+/// If the Var [takes `&form` and `&env`](Var::takes_form_env), the expander is passed `form`
+/// itself and a map from each local's symbol to its value before the call's arguments.
+///
+/// Locals shadow macros: a head symbol bound in `ctx` is never treated as a macro.
+fn try_expand_macro_call(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    form: &PtrValue,
+) -> Result<Option<PtrValue>, EvalError> {
+    let Value::List(list, _) = form.as_ref() else {
+        return Ok(None);
+    };
+    let Some(Value::Symbol(head, _)) = list.get_first().as_deref().cloned() else {
+        return Ok(None);
+    };
+    if let Symbol::Unqualified(head) = &head
+        && ctx.resolve_local(head.name()).is_some()
+    {
+        return Ok(None);
     }
-    result
+    let Some(var) = try_resolve(env.clone(), &head)
+        .ok()
+        .filter(|var| var.is_macro())
+    else {
+        return Ok(None);
+    };
+    let Some(macro_fn) = var
+        .deref()
+        .as_deref()
+        .and_then(value_optics::preview_function)
+    else {
+        return Ok(None);
+    };
+    let mut args = vec![];
+    if var.takes_form_env() {
+        let locals = ctx
+            .iter_locals()
+            .map(|(name, value)| (Value::symbol_unqualified_ptr(name), value.to_owned()))
+            .collect();
+        args.push(form.to_owned());
+        args.push(Value::map_ptr(Map::new(locals)));
+    }
+    args.extend(list.iter().skip(1).cloned());
+    macro_fn.try_invoke(env, ctx, args).map(Some)
+}
+
+/// Expands `form` once if it is a macro call, returning it unchanged otherwise.
+///
+/// Panics with the error's message if expansion fails; see [`try_macroexpand_1`] for a fallible
+/// version.
+pub fn macroexpand_1(env: PtrEnvironment, ctx: EvalContext, form: PtrValue) -> PtrValue {
+    try_macroexpand_1(env, ctx, form).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Expands `form` once if it is a macro call, returning it unchanged otherwise.
+///
+/// Only the form itself is expanded, not its subforms.
+pub fn try_macroexpand_1(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    form: PtrValue,
+) -> Result<PtrValue, EvalError> {
+    Ok(try_expand_macro_call(env, ctx, &form)?.unwrap_or(form))
+}
+
+/// Repeatedly expands `form` until it is no longer a macro call.
+///
+/// Panics with the error's message if expansion fails; see [`try_macroexpand`] for a fallible
+/// version.
+pub fn macroexpand(env: PtrEnvironment, ctx: EvalContext, form: PtrValue) -> PtrValue {
+    try_macroexpand(env, ctx, form).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Repeatedly expands `form` until it is no longer a macro call.
+///
+/// Only the form itself is expanded, not its subforms.
+pub fn try_macroexpand(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    form: PtrValue,
+) -> Result<PtrValue, EvalError> {
+    let mut form = form;
+    while let Some(expansion) = try_expand_macro_call(env.clone(), ctx.clone(), &form)? {
+        form = expansion;
+    }
+    Ok(form)
+}
+
+/// Special form: if
+/// (if test then) or (if test then else) -> evaluate test, then exactly one branch
+/// Only `nil` and `false` are falsey; the untaken branch is never evaluated.
+/// A missing else branch evaluates to nil. Both branches are in tail position.
+fn eval_if(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<Tail, EvalError> {
+    let arg_count = list.len() - 1;
+    if !(2..=3).contains(&arg_count) {
+        return Err(EvalError::incorrect(
+            Value::list_ptr(list.clone()),
+            format!(
+                "if: expected a test, a then branch and an optional else branch, got {arg_count} argument(s)"
+            ),
+        ));
+    }
+
+    let test = try_eval(env.clone(), ctx.clone(), list.get_nth_or_panic(1))?;
+    if test.is_truthy() {
+        eval_tail(env, ctx, list.get_nth_or_panic(2))
+    } else {
+        match list.get_nth(3) {
+            Some(else_expr) => eval_tail(env, ctx, else_expr),
+            None => Ok(Tail::Value(Value::nil_ptr())),
+        }
+    }
+}
+
+/// Special form: def
+/// (def name) (def name init) (def name "docstring" init)
+/// Interns a Var named `name` into the current namespace (reusing an existing one) and binds it
+/// to the value of `init`, if given. Metadata on the `name` symbol and the docstring (as `:doc`)
+/// are merged into the Var's metadata. Returns the Var itself, not its value.
+fn eval_def(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<PtrValue, EvalError> {
+    let form = || Value::list_ptr(list.clone());
+    let (name, docstring, init_expr) = match list.len() - 1 {
+        1 => (list.get_nth_or_panic(1), None, None),
+        2 => (list.get_nth_or_panic(1), None, list.get_nth(2)),
+        3 => {
+            let docstring = list.get_nth_or_panic(2);
+            if !docstring.is_string() {
+                return Err(EvalError::incorrect(
+                    form(),
+                    format!("def: docstring must be a string, got: {}", docstring),
+                ));
+            }
+            (list.get_nth_or_panic(1), Some(docstring), list.get_nth(3))
+        }
+        arg_count => {
+            return Err(EvalError::incorrect(
+                form(),
+                format!(
+                    "def: expected a name, an optional docstring and an optional init, got {arg_count} argument(s)"
+                ),
+            ));
+        }
+    };
+
+    let Value::Symbol(symbol, symbol_meta) = name.as_ref() else {
+        return Err(EvalError::incorrect(
+            form(),
+            format!("def: first argument must be a symbol, got: {}", name),
+        ));
+    };
+
+    let ns = env.try_get_current_namespace().map_err(|err| {
+        EvalError::not_found(
+            form(),
+            format!("def: could not determine current namespace: {:?}", err),
+        )
+    })?;
+    if let Some(symbol_ns) = symbol.namespace()
+        && symbol_ns != ns.name_str()
+    {
+        return Err(EvalError::incorrect(
+            form(),
+            format!(
+                "def: cannot def {} from namespace {}",
+                symbol,
+                ns.name_str()
+            ),
+        ));
+    }
+
+    // intern before evaluating init so that init can refer to the Var
+    let var = match ns.try_get_var(symbol.name()) {
+        Ok(var) => var,
+        Err(_) => {
+            let var = Arc::new(Var::new_unbound());
+            ns.insert_var(symbol.name(), var.clone());
+            var
+        }
+    };
+
+    let mut meta = var
+        .meta()
+        .map(|meta| meta.as_ref().clone())
+        .unwrap_or_else(Map::new_empty);
+    if let Some(symbol_meta) = symbol_meta {
+        for (k, v) in symbol_meta.iter() {
+            meta.insert(k.to_owned(), v.to_owned());
+        }
+    }
+    if let Some(docstring) = docstring {
+        meta.insert(Value::keyword_unqualified_ptr("doc"), docstring);
+    }
+    if !meta.is_empty() {
+        var.set_meta(Some(Arc::new(meta)));
+    }
+
+    if let Some(init_expr) = init_expr {
+        let value = try_eval(env, ctx, init_expr)?;
+        var.bind(value);
+    }
+
+    Ok(Value::var_ptr(var))
+}
+
+/// Special form: quote
+/// (quote form) -> form, without evaluating it
+fn eval_quote(list: &List) -> Result<PtrValue, EvalError> {
+    if list.len() != 2 {
+        return Err(EvalError::incorrect(
+            Value::list_ptr(list.clone()),
+            format!("quote: expected exactly 1 argument, got {}", list.len() - 1),
+        ));
+    }
+    Ok(list.get_nth_or_panic(1))
+}
+
+/// Special form: var
+/// (var sym) -> the Var sym resolves to in the environment, ignoring local bindings
+fn eval_var(env: PtrEnvironment, list: &List) -> Result<PtrValue, EvalError> {
+    let form = || Value::list_ptr(list.clone());
+    if list.len() != 2 {
+        return Err(EvalError::incorrect(
+            form(),
+            format!("var: expected exactly 1 argument, got {}", list.len() - 1),
+        ));
+    }
+    let target = list.get_nth_or_panic(1);
+    let Value::Symbol(symbol, _) = target.as_ref() else {
+        return Err(EvalError::incorrect(
+            form(),
+            format!("var: expected a symbol, got {}", target),
+        ));
+    };
+    try_resolve(env, symbol)
+        .map(Value::var_ptr)
+        .map_err(|err| EvalError::not_found(form(), err.to_string()))
+}
+
+/// Special form: do
+/// (do expr1 expr2 ... exprN) -> evaluate each in order, return value of exprN (or nil if empty)
+/// exprN is in tail position.
+fn eval_do(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<Tail, EvalError> {
+    let body_exprs: Vec<PtrValue> = list.iter().skip(1).map(|v| v.to_owned()).collect();
+    eval_body(env, ctx, &body_exprs)
 }
 
 /// Special form: let*
 /// (let* [binding1 expr1 binding2 expr2 ...] body_expr1 body_expr2 ...)
 /// Binds variables sequentially, later bindings can see earlier ones.
-fn eval_let_star(env: PtrEnvironment, ctx: EvalContext, list: &List) -> PtrValue {
-    // Extract binding vector and body
-    if let Some(binding_vec) = list.get_second() {
-        if let Value::Vector(bindings, _) = binding_vec.as_ref() {
-            // Validate even number of elements
-            if bindings.len() % 2 != 0 {
-                panic!("let*: binding vector must have an even number of elements");
+/// Each binding may be a destructuring pattern (see [`destructure`]).
+/// The last body expression is in tail position.
+fn eval_let_star(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<Tail, EvalError> {
+    let (_names, body_ctx) = eval_bindings("let*", env.clone(), ctx, list)?;
+    let body_exprs: Vec<PtrValue> = list.iter().skip(2).map(|v| v.to_owned()).collect();
+    eval_body(env, body_ctx, &body_exprs)
+}
+
+/// Special form: loop*
+/// (loop* [binding1 expr1 binding2 expr2 ...] body_expr1 body_expr2 ...)
+/// Binds like let* and establishes a recursion point: `(recur expr1 ... exprN)` in tail position
+/// of the body rebinds all N names and re-runs the body without growing the stack.
+fn eval_loop_star(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    list: &List,
+) -> Result<PtrValue, EvalError> {
+    let (patterns, mut loop_ctx) = eval_bindings("loop*", env.clone(), ctx, list)?;
+    let body_exprs: Vec<PtrValue> = list.iter().skip(2).map(|v| v.to_owned()).collect();
+
+    loop {
+        match eval_body(env.clone(), loop_ctx.clone(), &body_exprs)? {
+            Tail::Value(value) => return Ok(value),
+            Tail::Recur(args) => {
+                if args.len() != patterns.len() {
+                    return Err(EvalError::incorrect(
+                        Value::list_ptr(list.clone()),
+                        format!(
+                            "recur: expected {} argument(s) to match loop* bindings, got {}",
+                            patterns.len(),
+                            args.len()
+                        ),
+                    ));
+                }
+                for (pattern, arg) in patterns.iter().zip(args) {
+                    loop_ctx = destructure(env.clone(), loop_ctx, pattern, arg)?;
+                }
             }
+        }
+    }
+}
+
+/// Special form: recur
+/// (recur expr1 expr2 ...) -> evaluate each expr, then re-enter the nearest enclosing loop* or fn*
+/// with them as the new bindings. Only valid in tail position.
+fn eval_recur(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<Tail, EvalError> {
+    let args = list
+        .iter()
+        .skip(1)
+        .map(|value| try_eval(env.clone(), ctx.clone(), value.to_owned()))
+        .collect::<Result<Vec<PtrValue>, EvalError>>()?;
+    Ok(Tail::Recur(args))
+}
+
+/// Evaluate the binding vector of a let*-like form (its first argument) sequentially,
+/// returning the binding patterns in order together with the extended context.
+fn eval_bindings(
+    form_name: &str,
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    list: &List,
+) -> Result<(Vec<PtrValue>, EvalContext), EvalError> {
+    let form = || Value::list_ptr(list.clone());
+    let binding_vec = list.get_second();
+    let Some(Value::Vector(bindings, _)) = binding_vec.as_deref() else {
+        return Err(EvalError::incorrect(
+            form(),
+            format!("{form_name}: first argument must be a binding vector"),
+        ));
+    };
+    if !bindings.len().is_multiple_of(2) {
+        return Err(EvalError::incorrect(
+            form(),
+            format!("{form_name}: binding vector must have an even number of elements"),
+        ));
+    }
+
+    let mut patterns = Vec::with_capacity(bindings.len() / 2);
+    let mut ctx = ctx;
+    for pair in bindings.iter().collect::<Vec<_>>().chunks(2) {
+        let (pattern, init_expr) = (pair[0], pair[1]);
+        // Evaluate init expression with the context extended by the previous bindings
+        let value = try_eval(env.clone(), ctx.clone(), init_expr.to_owned())?;
+        ctx = destructure(env.clone(), ctx, pattern, value)?;
+        patterns.push(pattern.to_owned());
+    }
+    Ok((patterns, ctx))
+}
 
-            // Process bindings sequentially with context threading
-            let body_exprs: Vec<PtrValue> = list.iter().skip(2).map(|v| v.to_owned()).collect();
+/// Bind `pattern` to `value` on top of `ctx`, destructuring as `let*`, `loop*` and `fn*` do:
+/// - A symbol binds the whole value.
+/// - A vector `[a b & rest :as all]` binds the elements of a list or vector (or nil) by position,
+///   `rest` to a list of the remaining elements (nil if there are none), and `all` to the value.
+/// - A map `{a :a, :keys [b] :strs [c] :syms [d] :or {b 0} :as m}` binds values looked up in a
+///   map (or nil, or a list or vector of key-value pairs) by key: `a` by the key `:a`, `b` by
+///   `:b`, `c` by `"c"` and `d` by `'d`. `:or` gives defaults for names whose keys are missing,
+///   and `m` binds the value. `:ns/keys [e]` looks up `e` by `:ns/e`.
+///
+/// Patterns nest arbitrarily. Default expressions are evaluated only when needed.
+fn destructure(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    pattern: &PtrValue,
+    value: PtrValue,
+) -> Result<EvalContext, EvalError> {
+    match pattern.as_ref() {
+        Value::Symbol(Symbol::Unqualified(name), _) => {
+            Ok(ctx.with_local(name.name().to_string(), value))
+        }
+        Value::Vector(patterns, _) => destructure_sequential(env, ctx, pattern, patterns, value),
+        Value::Map(patterns, _) => destructure_associative(env, ctx, pattern, patterns, value),
+        _ => Err(EvalError::incorrect(
+            pattern.to_owned(),
+            format!(
+                "binding pattern must be an unqualified symbol, a vector or a map, got: {}",
+                pattern
+            ),
+        )),
+    }
+}
+
+fn destructure_sequential(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    pattern: &PtrValue,
+    patterns: &Vector,
+    value: PtrValue,
+) -> Result<EvalContext, EvalError> {
+    let elements: Vec<PtrValue> = match value.as_ref() {
+        Value::Nil(_) => vec![],
+        Value::List(list, _) => list.iter().cloned().collect(),
+        Value::Vector(vector, _) => vector.iter().cloned().collect(),
+        _ => {
+            return Err(EvalError::incorrect(
+                pattern.to_owned(),
+                format!("cannot destructure {} as a sequence", value),
+            ));
+        }
+    };
 
-            if body_exprs.is_empty() {
-                return Value::nil_ptr();
+    let patterns: Vec<PtrValue> = patterns.iter().cloned().collect();
+    let mut ctx = ctx;
+    let mut position = 0;
+    let mut i = 0;
+    while i < patterns.len() {
+        let element_pattern = &patterns[i];
+        let is_rest = marker_symbol_name(element_pattern) == Some("&");
+        let is_as = value_optics::preview_keyword_unqualified(element_pattern)
+            .is_some_and(|kw| kw.name() == "as");
+        if is_rest || is_as {
+            let Some(next_pattern) = patterns.get(i + 1) else {
+                return Err(EvalError::incorrect(
+                    pattern.to_owned(),
+                    format!("{} must be followed by a binding pattern", element_pattern),
+                ));
+            };
+            if is_rest {
+                let rest = &elements[position.min(elements.len())..];
+                let rest = if rest.is_empty() {
+                    Value::nil_ptr()
+                } else {
+                    Value::list_ptr(List::from(rest.to_vec()))
+                };
+                ctx = destructure(env.clone(), ctx, next_pattern, rest)?;
+            } else {
+                ctx = destructure(env.clone(), ctx, next_pattern, value.clone())?;
             }
+            i += 2;
+        } else {
+            let element = elements
+                .get(position)
+                .cloned()
+                .unwrap_or_else(Value::nil_ptr);
+            ctx = destructure(env.clone(), ctx, element_pattern, element)?;
+            position += 1;
+            i += 1;
+        }
+    }
+    Ok(ctx)
+}
 
-            // Helper function to process bindings recursively
-            fn process_bindings(
-                env: PtrEnvironment,
-                ctx: EvalContext,
-                bindings: &Vector,
-                binding_index: usize,
-                body_exprs: &[PtrValue],
-            ) -> PtrValue {
-                if binding_index >= bindings.len() {
-                    // All bindings processed, evaluate body
-                    let mut res = Value::nil_ptr();
-                    for expr in body_exprs {
-                        res = eval(env.clone(), ctx.clone(), expr.to_owned());
-                    }
-                    return res;
+fn destructure_associative(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    pattern: &PtrValue,
+    patterns: &Map,
+    value: PtrValue,
+) -> Result<EvalContext, EvalError> {
+    let incorrect = |message: String| EvalError::incorrect(pattern.to_owned(), message);
+    let map = match value.as_ref() {
+        Value::Nil(_) => Map::new_empty(),
+        Value::Map(map, _) => map.to_owned(),
+        // e.g. keyword arguments collected by `& {:keys [..]}`
+        Value::List(_, _) | Value::Vector(_, _) => {
+            let elements: Vec<PtrValue> = match value.as_ref() {
+                Value::List(list, _) => list.iter().cloned().collect(),
+                Value::Vector(vector, _) => vector.iter().cloned().collect(),
+                _ => unreachable!(),
+            };
+            if !elements.len().is_multiple_of(2) {
+                return Err(incorrect(format!(
+                    "cannot destructure {} as a map, it has an odd number of elements",
+                    value
+                )));
+            }
+            Map::new(
+                elements
+                    .chunks(2)
+                    .map(|pair| (pair[0].to_owned(), pair[1].to_owned()))
+                    .collect(),
+            )
+        }
+        _ => return Err(incorrect(format!("cannot destructure {} as a map", value))),
+    };
+
+    let defaults = match patterns.get(&Value::keyword_unqualified_ptr("or")) {
+        None => Map::new_empty(),
+        Some(defaults) => match defaults.as_ref() {
+            Value::Map(defaults, _) => defaults.to_owned(),
+            _ => return Err(incorrect(format!(":or must be a map, got: {}", defaults))),
+        },
+    };
+
+    // Bind `local_pattern` to the value under `key`, falling back to its `:or` default
+    let bind_key = |ctx: EvalContext, local_pattern: &PtrValue, key: &PtrValue| {
+        let found = match map.get(key) {
+            Some(found) => found,
+            None => match defaults.get(local_pattern) {
+                Some(default_expr) => try_eval(env.clone(), ctx.clone(), default_expr)?,
+                None => Value::nil_ptr(),
+            },
+        };
+        destructure(env.clone(), ctx, local_pattern, found)
+    };
+
+    let mut ctx = ctx;
+    for (k, v) in patterns.iter() {
+        match value_optics::preview_keyword(k) {
+            Some(kw) if kw.is_unqualified() && kw.name() == "or" => {}
+            Some(kw) if kw.is_unqualified() && kw.name() == "as" => {
+                ctx = destructure(env.clone(), ctx, v, value.clone())?;
+            }
+            Some(kw) if matches!(kw.name(), "keys" | "strs" | "syms") => {
+                let Value::Vector(names, _) = v.as_ref() else {
+                    return Err(incorrect(format!("{} must be a vector, got: {}", k, v)));
+                };
+                for name in names.iter() {
+                    let (name_ns, local_name) = match name.as_ref() {
+                        Value::Symbol(sym, _) => (sym.namespace(), sym.name()),
+                        Value::Keyword(kw, _) => (kw.namespace(), kw.name()),
+                        _ => {
+                            return Err(incorrect(format!(
+                                "{} names must be symbols, got: {}",
+                                k, name
+                            )));
+                        }
+                    };
+                    let key_ns = name_ns.or(kw.namespace());
+                    let key = match (kw.name(), key_ns) {
+                        ("keys", Some(ns)) => Value::keyword_qualified_ptr(ns, local_name),
+                        ("keys", None) => Value::keyword_unqualified_ptr(local_name),
+                        ("strs", _) => Value::string_ptr(local_name.to_owned()),
+                        (_, Some(ns)) => Value::symbol_ptr(Symbol::new_qualified(ns, local_name)),
+                        (_, None) => Value::symbol_unqualified_ptr(local_name),
+                    };
+                    ctx = bind_key(ctx, &Value::symbol_unqualified_ptr(local_name), &key)?;
                 }
+            }
+            _ => {
+                let key = try_eval(env.clone(), ctx.clone(), v.to_owned())?;
+                ctx = bind_key(ctx, k, &key)?;
+            }
+        }
+    }
+    Ok(ctx)
+}
 
-                // Get next binding pair
-                let var_name = &bindings.get_nth(binding_index).expect("let* binding name");
-                let init_expr = &bindings
-                    .get_nth(binding_index + 1)
-                    .expect("let* binding init");
+/// Evaluate a body in order, returning nil if it is empty. Only the last expression is in tail
+/// position.
+fn eval_body(
+    env: PtrEnvironment,
+    ctx: EvalContext,
+    body_exprs: &[PtrValue],
+) -> Result<Tail, EvalError> {
+    let Some((last, init)) = body_exprs.split_last() else {
+        return Ok(Tail::Value(Value::nil_ptr()));
+    };
+    for expr in init {
+        try_eval(env.clone(), ctx.clone(), expr.to_owned())?;
+    }
+    eval_tail(env, ctx, last.to_owned())
+}
+
+/// Special form: throw
+/// (throw ex) -> evaluate ex, which must be an ex-info map, and unwind to the nearest matching catch
+fn eval_throw(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<PtrValue, EvalError> {
+    let form = || Value::list_ptr(list.clone());
+    if list.len() != 2 {
+        return Err(EvalError::incorrect(
+            form(),
+            format!("throw: expected exactly 1 argument, got {}", list.len() - 1),
+        ));
+    }
+
+    let ex = try_eval(env, ctx, list.get_nth_or_panic(1))?;
+    match preview_ex_info(&ex) {
+        Some(anomaly) => Err(EvalError::thrown(form(), anomaly)),
+        None => Err(EvalError::incorrect(
+            form(),
+            format!(
+                "throw: expected a map with :jinme.anomalies/category and :jinme.anomalies/message, got: {}",
+                ex
+            ),
+        )),
+    }
+}
 
-                // Evaluate init expression with current context
-                let value = eval(env.clone(), ctx.clone(), init_expr.to_owned());
+/// Special form: try
+/// (try expr* (catch matcher name expr*)* (finally expr*)?)
+/// Evaluates the exprs like do. If that fails, the error's ex-info map (the thrown map, or one built
+/// from the error's category and message) is offered to each catch in order. `matcher` is evaluated
+/// and matches if it is a keyword equal to the map's :jinme.anomalies/category, or a function that
+/// returns truthy for the map. The first matching catch binds the map to `name` and evaluates its
+/// exprs; if none match, the error propagates. A matcher that fails, or is neither a keyword nor a
+/// function, matches nothing and the error propagates. The finally exprs always run last, for side
+/// effects.
+fn eval_try(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<PtrValue, EvalError> {
+    let form = || Value::list_ptr(list.clone());
 
-                // Extract symbol name and extend context
-                if let Some(_sym) = value_optics::preview_symbol_unqualified(var_name) {
-                    todo!()
+    let mut body_exprs = vec![];
+    let mut catch_clauses = vec![];
+    let mut finally_exprs = None;
+    for expr in list.iter().skip(1) {
+        let clause_list = match expr.as_ref() {
+            Value::List(clause_list, _) => Some(clause_list),
+            _ => None,
+        };
+        let clause_head = clause_list.and_then(List::get_first);
+        match clause_head.as_deref().and_then(marker_symbol_name) {
+            Some("catch") => {
+                let clause_list = clause_list.unwrap();
+                if finally_exprs.is_some() {
+                    return Err(EvalError::incorrect(
+                        form(),
+                        "try: finally clause must be last".to_owned(),
+                    ));
                 }
-                if let Value::Symbol(Symbol::Unqualified(sym_unq), _) = var_name.as_ref() {
-                    let var_name_str = sym_unq.name().to_string();
-                    let new_ctx = ctx.with_local(var_name_str, value);
-
-                    // Continue processing remaining bindings with extended context
-                    process_bindings(
-                        env.clone(),
-                        new_ctx,
-                        bindings,
-                        binding_index + 2,
-                        body_exprs,
-                    )
-                } else {
-                    panic!("let*: binding name must be a symbol");
+                if clause_list.len() < 3 {
+                    return Err(EvalError::incorrect(
+                        expr.to_owned(),
+                        "catch: expected a matcher and a binding name".to_owned(),
+                    ));
+                }
+                let matcher = clause_list.get_nth_or_panic(1);
+                if !matches!(
+                    matcher.as_ref(),
+                    Value::Keyword(..) | Value::Symbol(..) | Value::List(..)
+                ) {
+                    return Err(EvalError::incorrect(
+                        expr.to_owned(),
+                        format!(
+                            "catch: matcher must be a keyword or a function, got: {}",
+                            matcher
+                        ),
+                    ));
                 }
+                let name = clause_list.get_nth_or_panic(2);
+                let Some(name) = value_optics::preview_symbol_unqualified(&name) else {
+                    return Err(EvalError::incorrect(
+                        expr.to_owned(),
+                        format!("catch: binding name must be a symbol, got: {}", name),
+                    ));
+                };
+                catch_clauses.push((matcher, name, clause_list));
+            }
+            Some("finally") => {
+                if finally_exprs.is_some() {
+                    return Err(EvalError::incorrect(
+                        form(),
+                        "try: only one finally clause is allowed".to_owned(),
+                    ));
+                }
+                finally_exprs = Some(
+                    clause_list
+                        .unwrap()
+                        .iter()
+                        .skip(1)
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                );
+            }
+            _ => {
+                if !catch_clauses.is_empty() || finally_exprs.is_some() {
+                    return Err(EvalError::incorrect(
+                        form(),
+                        "try: body expressions must precede catch and finally clauses".to_owned(),
+                    ));
+                }
+                body_exprs.push(expr.to_owned());
             }
-
-            return process_bindings(env, ctx, bindings, 0, &body_exprs);
         }
     }
 
-    panic!("let*: first argument must be a binding vector");
+    let result = eval_body(env.clone(), ctx.clone(), &body_exprs)
+        .and_then(|tail| tail.into_value(&form()))
+        .or_else(|err| {
+            let ex = Value::map_ptr(err.to_anomaly_map().into_inner());
+            for (matcher, name, clause_list) in &catch_clauses {
+                let matches =
+                    try_eval(env.clone(), ctx.clone(), matcher.to_owned()).and_then(|matcher| {
+                        match matcher.as_ref() {
+                            Value::Keyword(keyword, _) => {
+                                Ok(keyword.as_unqualified_keyword() == Some(err.category()))
+                            }
+                            _ => try_apply(env.clone(), ctx.clone(), matcher, vec![ex.clone()])
+                                .map(|matched| matched.is_truthy()),
+                        }
+                    });
+                // a matcher that fails must not hide the error being handled
+                let Ok(matches) = matches else {
+                    return Err(err);
+                };
+                if matches {
+                    let catch_ctx = ctx.with_local(name.name().to_string(), ex.clone());
+                    let catch_exprs: Vec<PtrValue> = clause_list.iter().skip(3).cloned().collect();
+                    return eval_body(env.clone(), catch_ctx, &catch_exprs)?.into_value(&form());
+                }
+            }
+            Err(err)
+        });
+
+    if let Some(finally_exprs) = finally_exprs {
+        eval_body(env, ctx, &finally_exprs)?.into_value(&form())?;
+    }
+    result
+}
+
+/// Returns the value as an [`AnomalyMap`] if it is an `ex-info` map: a map whose
+/// `:jinme.anomalies/category` is an unqualified keyword and whose `:jinme.anomalies/message` is
+/// a string.
+pub fn preview_ex_info(value: &Value) -> Option<AnomalyMap> {
+    let map = value.preview_map_ref()?;
+    let category = map.get(&Value::keyword_qualified_ptr("jinme.anomalies", "category"))?;
+    let message = map.get(&Value::keyword_qualified_ptr("jinme.anomalies", "message"))?;
+    if value_optics::preview_keyword_unqualified(&category).is_some() && message.is_string() {
+        Some(AnomalyMap::from(map.clone()))
+    } else {
+        None
+    }
 }
 
 /// Special form: fn*
 /// (fn* [param1 param2 ...] body_expr1 body_expr2 ...)
 /// (fn* ([param1] body) ([param1 param2] body2) ...) for multiple arities
+/// (fn* name [param1 ...] body ...) binds `name` to the function itself inside its body
 /// Creates a closure that captures the current evaluation context.
-fn eval_fn_star(env: PtrEnvironment, ctx: EvalContext, list: &List) -> PtrValue {
+/// Parameters, including the variadic one, may be destructuring patterns (see [`destructure`]).
+fn eval_fn_star(
+    _env: PtrEnvironment,
+    ctx: EvalContext,
+    list: &List,
+) -> Result<PtrValue, EvalError> {
+    let def = parse_fn_star(list)?;
     // Capture the current context at function definition time
-    let captured_ctx = ctx;
-
-    // Check if second element is a vector (single arity) or list (multi-arity)
-    if let Some(second) = list.get_second() {
-        match second.as_ref() {
-            Value::Vector(params_vec, _) => {
-                // Single-arity fn*
-                let params = extract_params(params_vec);
-                let body: Vec<PtrValue> = list.iter().skip(2).map(|v| v.to_owned()).collect();
-                return create_fn_closure(env, captured_ctx, vec![(params, body)]);
+    let group = Arc::new(FnStarGroup {
+        captured_ctx: ctx,
+        defs: vec![def],
+        functions: OnceLock::new(),
+    });
+    let function = build_fn_star_group(&group).remove(0);
+    Ok(Value::function_ptr(function))
+}
+
+/// Special form: letfn*
+/// (letfn* [name1 (fn* name1 [params] body ...) name2 (fn* ...) ...] body_expr1 body_expr2 ...)
+/// Binds each name to its function. Every function can refer to all of the names, so they may
+/// be mutually recursive. The last body expression is in tail position.
+fn eval_letfn_star(env: PtrEnvironment, ctx: EvalContext, list: &List) -> Result<Tail, EvalError> {
+    let form = || Value::list_ptr(list.clone());
+    let binding_vec = list.get_second();
+    let Some(Value::Vector(bindings, _)) = binding_vec.as_deref() else {
+        return Err(EvalError::incorrect(
+            form(),
+            "letfn*: first argument must be a binding vector".to_owned(),
+        ));
+    };
+    if !bindings.len().is_multiple_of(2) {
+        return Err(EvalError::incorrect(
+            form(),
+            "letfn*: binding vector must have an even number of elements".to_owned(),
+        ));
+    }
+
+    let mut names = Vec::with_capacity(bindings.len() / 2);
+    let mut defs = Vec::with_capacity(bindings.len() / 2);
+    for pair in bindings.iter().collect::<Vec<_>>().chunks(2) {
+        let (name, init_expr) = (pair[0], pair[1]);
+        let Some(name) = value_optics::preview_symbol_unqualified(name) else {
+            return Err(EvalError::incorrect(
+                form(),
+                format!(
+                    "letfn*: binding name must be an unqualified symbol, got: {}",
+                    name
+                ),
+            ));
+        };
+        let fn_list = match init_expr.as_ref() {
+            Value::List(fn_list, _)
+                if fn_list
+                    .get_first()
+                    .as_deref()
+                    .and_then(value_optics::preview_symbol_unqualified)
+                    .is_some_and(|head| head.name() == "fn*") =>
+            {
+                fn_list
             }
-            Value::List(_, _) => {
-                // Multi-arity fn*
-                let mut arities = vec![];
-                let forms: Vec<PtrValue> = list.iter().skip(1).map(|v| v.to_owned()).collect();
-
-                for form in forms {
-                    if let Value::List(form_list, _) = form.as_ref() {
-                        if let Some(param_vec) = form_list.get_first() {
-                            if let Value::Vector(params_vec, _) = param_vec.as_ref() {
-                                let params = extract_params(params_vec);
-                                let body: Vec<PtrValue> =
-                                    form_list.iter().skip(1).map(|v| v.to_owned()).collect();
-                                arities.push((params, body));
-                            } else {
-                                panic!("fn*: multi-arity form parameters must be a vector");
-                            }
+            _ => {
+                return Err(EvalError::incorrect(
+                    form(),
+                    format!(
+                        "letfn*: binding init must be a fn* form, got: {}",
+                        init_expr
+                    ),
+                ));
+            }
+        };
+        let mut def = parse_fn_star(fn_list)?;
+        def.name = Some(name.name().to_owned());
+        names.push(name.name().to_owned());
+        defs.push(def);
+    }
+
+    let group = Arc::new(FnStarGroup {
+        captured_ctx: ctx.clone(),
+        defs,
+        functions: OnceLock::new(),
+    });
+    let functions = build_fn_star_group(&group);
+    let body_ctx = ctx.with_locals(
+        names
+            .into_iter()
+            .zip(functions)
+            .map(|(name, function)| (name, Value::function_ptr(function)))
+            .collect(),
+    );
+    let body_exprs: Vec<PtrValue> = list.iter().skip(2).cloned().collect();
+    eval_body(env, body_ctx, &body_exprs)
+}
+
+/// Parse the name (if any) and arities of a `fn*` form.
+fn parse_fn_star(list: &List) -> Result<FnStarDef, EvalError> {
+    let form = || Value::list_ptr(list.clone());
+    let mut forms: Vec<PtrValue> = list.iter().skip(1).map(|v| v.to_owned()).collect();
+
+    // An optional leading symbol names the function
+    let mut name = None;
+    if let Some(Value::Symbol(symbol, _)) = forms.first().map(|v| v.as_ref()) {
+        let Symbol::Unqualified(symbol) = symbol else {
+            return Err(EvalError::incorrect(
+                form(),
+                format!("fn*: name must be an unqualified symbol, got: {}", symbol),
+            ));
+        };
+        name = Some(symbol.name().to_owned());
+        forms.remove(0);
+    }
+
+    // Check if the next element is a vector (single arity) or list (multi-arity)
+    let Some(first) = forms.first() else {
+        return Err(EvalError::incorrect(
+            form(),
+            "fn*: missing parameters".to_owned(),
+        ));
+    };
+    let mut arities = vec![];
+    match first.as_ref() {
+        Value::Vector(params_vec, _) => {
+            // Single-arity fn*
+            let params = extract_params(params_vec)
+                .map_err(|message| EvalError::incorrect(form(), message))?;
+            let body: Vec<PtrValue> = forms.iter().skip(1).map(|v| v.to_owned()).collect();
+            arities.push((params, body));
+        }
+        Value::List(_, _) => {
+            // Multi-arity fn*
+            for arity_form in forms {
+                if let Value::List(form_list, _) = arity_form.as_ref() {
+                    if let Some(param_vec) = form_list.get_first() {
+                        if let Value::Vector(params_vec, _) = param_vec.as_ref() {
+                            let params = extract_params(params_vec)
+                                .map_err(|message| EvalError::incorrect(form(), message))?;
+                            let body: Vec<PtrValue> =
+                                form_list.iter().skip(1).map(|v| v.to_owned()).collect();
+                            arities.push((params, body));
+                        } else {
+                            return Err(EvalError::incorrect(
+                                form(),
+                                "fn*: multi-arity form parameters must be a vector".to_owned(),
+                            ));
                         }
-                    } else {
-                        panic!("fn*: multi-arity forms must be lists");
                     }
+                } else {
+                    return Err(EvalError::incorrect(
+                        form(),
+                        "fn*: multi-arity forms must be lists".to_owned(),
+                    ));
                 }
-
-                return create_fn_closure(env, captured_ctx, arities);
-            }
-            _ => {
-                panic!(
-                    "fn*: first argument must be a parameter vector or list of parameter vectors"
-                );
             }
         }
+        _ => {
+            return Err(EvalError::incorrect(
+                form(),
+                "fn*: first argument must be a parameter vector or list of parameter vectors"
+                    .to_owned(),
+            ));
+        }
     }
 
-    panic!("fn*: missing parameters");
+    Ok(FnStarDef { name, arities })
 }
 
-/// Extract parameter names from a parameter vector.
-/// Supports variadic syntax: [a b & rest] -> (["a", "b"], Some("rest"))
-fn extract_params(params_vec: &Vector) -> FnParams {
-    let mut regular_params = vec![]; // TODO: support destructuring
-    let mut variadic_param: Option<String> = None; // TODO: support destructuring
+/// Extract parameter patterns from a parameter vector.
+/// Supports variadic syntax: [a b & rest] -> ([a, b], Some(rest))
+/// Each parameter may be any pattern accepted by [`destructure`].
+fn extract_params(params_vec: &Vector) -> Result<FnParams, String> {
+    let mut regular_params = vec![];
+    let mut variadic_param: Option<PtrValue> = None;
     let mut saw_ampersand = false;
 
     for param_val in params_vec.iter() {
-        if let Value::Symbol(Symbol::Unqualified(sym), _) = param_val.as_ref() {
-            if sym.name() == "&" {
-                saw_ampersand = true;
-            } else if saw_ampersand {
-                variadic_param = Some(sym.name().to_string());
-                saw_ampersand = false;
-            } else {
-                regular_params.push(sym.name().to_string());
-            }
+        let is_ampersand = marker_symbol_name(param_val) == Some("&");
+        if variadic_param.is_some() {
+            return Err(format!(
+                "fn*: unexpected parameter after variadic parameter: {}",
+                param_val
+            ));
+        } else if is_ampersand {
+            saw_ampersand = true;
+        } else if saw_ampersand {
+            variadic_param = Some(param_val.to_owned());
         } else {
-            panic!("fn*: parameter must be a symbol");
+            regular_params.push(param_val.to_owned());
         }
     }
+    if saw_ampersand && variadic_param.is_none() {
+        return Err("fn*: & must be followed by a parameter".to_owned());
+    }
 
-    FnParams {
+    Ok(FnParams {
         regular: regular_params,
         variadic: variadic_param,
-    }
+    })
 }
 
 #[derive(Clone, Debug)]
 struct FnParams {
-    regular: Vec<String>,
-    variadic: Option<String>,
+    regular: Vec<PtrValue>,
+    variadic: Option<PtrValue>,
 }
 
-fn create_fn_closure(
-    env: PtrEnvironment,
-    captured_ctx: EvalContext,
+/// The name (if any) and arities of a `fn*` form.
+struct FnStarDef {
+    name: Option<String>,
     arities: Vec<(FnParams, Vec<PtrValue>)>,
-) -> PtrValue {
-    let mut func_builder = Function::builder();
+}
 
-    for (params, body) in arities {
-        let arity = if let Some(ref _variadic) = params.variadic {
-            FunctionArity::AtLeast(params.regular.len())
-        } else {
-            FunctionArity::Exactly(params.regular.len())
-        };
+/// Functions defined together by a single `fn*` or `letfn*`, sharing the captured context.
+///
+/// Named functions are bound inside every function body of the group when it is invoked. The
+/// group holds its built functions only weakly, so self- and mutual references don't form
+/// reference cycles.
+struct FnStarGroup {
+    captured_ctx: EvalContext,
+    defs: Vec<FnStarDef>,
+    /// The functions built from `defs`, in the same order, set once by [`build_fn_star_group`]
+    functions: OnceLock<Vec<Weak<Function>>>,
+}
 
-        let _env_clone = env.clone();
-        let params_clone = params.clone();
-        let body_clone = body.clone();
-        let ctx_clone = captured_ctx.clone();
-        let arity_clone = arity.clone();
-
-        let closure = closure_fn(
-            arity,
-            move |fn_env: PtrEnvironment, _fn_ctx: EvalContext, args: Vec<PtrValue>| {
-                // Bind parameters to arguments
-                let mut fn_ctx = ctx_clone.clone();
-
-                // Bind regular parameters
-                for (i, param_name) in params_clone.regular.iter().enumerate() {
-                    if i < args.len() {
-                        fn_ctx = fn_ctx.with_local(param_name.clone(), args[i].clone());
-                    } else {
-                        panic!("fn*: not enough arguments");
-                    }
-                }
+/// Build the functions of `group`, once, so that every call of one of them sees the same
+/// sibling functions.
+fn build_fn_star_group(group: &Arc<FnStarGroup>) -> Vec<PtrFunction> {
+    let functions: Vec<PtrFunction> = (0..group.defs.len())
+        .map(|index| build_fn_star(group, index))
+        .collect();
+    if group
+        .functions
+        .set(functions.iter().map(Arc::downgrade).collect())
+        .is_err()
+    {
+        unreachable!("the functions of a fn* group are built once");
+    }
+    functions
+}
 
-                // Bind variadic parameter if present
-                if let Some(ref variadic_name) = params_clone.variadic {
-                    let rest_args = args[params_clone.regular.len()..].to_vec();
-                    let rest_list = Value::list_ptr(List::from(rest_args));
-                    fn_ctx = fn_ctx.with_local(variadic_name.clone(), rest_list);
-                } else if args.len() > params_clone.regular.len() {
-                    panic!("fn*: too many arguments");
-                }
+/// Build the function for `group.defs[index]`, named after its definition.
+fn build_fn_star(group: &Arc<FnStarGroup>, index: usize) -> PtrFunction {
+    let def = &group.defs[index];
+    Arc::new_cyclic(|this| {
+        let mut func_builder = Function::builder();
+        if let Some(name) = &def.name {
+            func_builder.set_name(name.clone());
+        }
 
-                // Evaluate body with extended context
-                let mut res = Value::nil_ptr();
-                for expr in &body_clone {
-                    res = eval(fn_env.clone(), fn_ctx.clone(), expr.to_owned());
-                }
-                res
-            },
-        );
+        for (arity_index, (params, _body)) in def.arities.iter().enumerate() {
+            let arity = if let Some(ref _variadic) = params.variadic {
+                FunctionArity::AtLeast(params.regular.len())
+            } else {
+                FunctionArity::Exactly(params.regular.len())
+            };
+
+            func_builder.add_body(
+                arity,
+                FnStarBody {
+                    group: group.clone(),
+                    index,
+                    arity_index,
+                    this: this.clone(),
+                },
+            );
+        }
+
+        func_builder.build()
+    })
+}
+
+/// One arity of a `fn*` closure. Its body is evaluated with [`try_eval`], so errors propagate
+/// through [`IFunction::try_invoke`] instead of unwinding.
+struct FnStarBody {
+    group: Arc<FnStarGroup>,
+    index: usize,
+    arity_index: usize,
+    /// The function this body belongs to, bound to its name when invoked
+    this: Weak<Function>,
+}
+
+impl FnStarBody {
+    fn params(&self) -> &FnParams {
+        &self.group.defs[self.index].arities[self.arity_index].0
+    }
 
-        func_builder.add_ifunction(arity_clone, closure.1);
+    fn body(&self) -> &[PtrValue] {
+        &self.group.defs[self.index].arities[self.arity_index].1
     }
 
-    Value::function_ptr(Arc::new(func_builder.build()))
+    /// The captured context, extended with the named functions of the group. A sibling that has
+    /// since been dropped, because only this function escaped its `letfn*`, is built anew.
+    fn base_ctx(&self) -> EvalContext {
+        let mut ctx = self.group.captured_ctx.clone();
+        let functions = self.group.functions.get();
+        for (i, def) in self.group.defs.iter().enumerate() {
+            if let Some(name) = &def.name {
+                let function = if i == self.index {
+                    self.this.upgrade()
+                } else {
+                    functions.and_then(|functions| functions[i].upgrade())
+                }
+                .unwrap_or_else(|| build_fn_star(&self.group, i));
+                ctx = ctx.with_local(name.clone(), Value::function_ptr(function));
+            }
+        }
+        ctx
+    }
 }
 
-pub fn try_resolve(env: PtrEnvironment, symbol: &Symbol) -> Result<PtrVar, ResolveError> {
-    match symbol {
-        Symbol::Qualified(sym) => {
-            // log::info!("Resolving qualified symbol: {}", sym);
-            env.try_get_namespace(sym.namespace())
-                .ok_or_else(|| {
-                    ResolveError::NoSuchNamespace(SymbolUnqualified::new(sym.namespace()))
-                })?
-                .try_get_var(sym.name())
-                .map_err(ResolveError::from)
+impl IFunction for FnStarBody {
+    fn invoke(&self, env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>) -> PtrValue {
+        self.try_invoke(env, ctx, args)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_invoke(
+        &self,
+        fn_env: PtrEnvironment,
+        _fn_ctx: EvalContext,
+        args: Vec<PtrValue>,
+    ) -> Result<PtrValue, EvalError> {
+        let args_form = || Value::list_ptr(List::from(args.clone()));
+
+        // Bind parameters to arguments
+        let base_ctx = self.base_ctx();
+        let mut fn_ctx = base_ctx.clone();
+        let params = self.params();
+
+        // Bind regular parameters
+        for (i, param) in params.regular.iter().enumerate() {
+            if i < args.len() {
+                fn_ctx = destructure(fn_env.clone(), fn_ctx, param, args[i].clone())?;
+            } else {
+                return Err(EvalError::incorrect(
+                    args_form(),
+                    "fn*: not enough arguments".to_owned(),
+                ));
+            }
         }
-        Symbol::Unqualified(sym) => {
-            // log::info!("Resolving unqualified symbol: {}", sym);
-            env.try_get_current_namespace()
-                .map_err(|_| ResolveError::UnknownCurrentNamespace)?
-                .try_get_var(sym.name())
-                .map_err(ResolveError::from)
+
+        // Bind variadic parameter if present
+        if let Some(ref variadic) = params.variadic {
+            let rest_args = args[params.regular.len()..].to_vec();
+            let rest_list = Value::list_ptr(List::from(rest_args));
+            fn_ctx = destructure(fn_env.clone(), fn_ctx, variadic, rest_list)?;
+        } else if args.len() > params.regular.len() {
+            return Err(EvalError::incorrect(
+                args_form(),
+                "fn*: too many arguments".to_owned(),
+            ));
+        }
+
+        // Evaluate body with extended context, re-running it on recur
+        loop {
+            match eval_body(fn_env.clone(), fn_ctx, self.body())? {
+                Tail::Value(value) => return Ok(value),
+                Tail::Recur(recur_args) => {
+                    // recur takes one argument per parameter; the variadic parameter is
+                    // rebound to its argument as-is rather than collecting the rest
+                    let recur_params: Vec<&PtrValue> = params
+                        .regular
+                        .iter()
+                        .chain(params.variadic.iter())
+                        .collect();
+                    if recur_args.len() != recur_params.len() {
+                        return Err(EvalError::incorrect(
+                            Value::list_ptr(List::from(recur_args.clone())),
+                            format!(
+                                "recur: expected {} argument(s) to match fn* parameters, got {}",
+                                recur_params.len(),
+                                recur_args.len()
+                            ),
+                        ));
+                    }
+                    fn_ctx = base_ctx.clone();
+                    for (param, arg) in recur_params.into_iter().zip(recur_args) {
+                        fn_ctx = destructure(fn_env.clone(), fn_ctx, param, arg)?;
+                    }
+                }
+            }
         }
     }
 }
 
-pub fn resolve_or_panic(env: PtrEnvironment, symbol: &Symbol) -> PtrVar {
+pub fn try_resolve(env: PtrEnvironment, symbol: &Symbol) -> Result<PtrVar, ResolveError> {
     match symbol {
         Symbol::Qualified(sym) => {
             // log::info!("Resolving qualified symbol: {}", sym);
             env.try_get_namespace(sym.namespace())
                 .ok_or_else(|| {
                     ResolveError::NoSuchNamespace(SymbolUnqualified::new(sym.namespace()))
-                })
-                .expect(&format!("could not find namespace: {}", sym.namespace()))
+                })?
                 .try_get_var(sym.name())
-                .expect(&format!("could not resolve var: {}", sym))
+                .map_err(ResolveError::from)
         }
         Symbol::Unqualified(sym) => {
             // log::info!("Resolving unqualified symbol: {}", sym);
             env.try_get_current_namespace()
-                .map_err(|_| ResolveError::UnknownCurrentNamespace)
-                .expect(&format!("could not determine current namespace"))
+                .map_err(|_| ResolveError::UnknownCurrentNamespace)?
                 .try_get_var(sym.name())
-                .expect(&format!("could not resolve var: {}", sym))
+                .map_err(ResolveError::from)
         }
     }
 }
 
+/// Like [`try_resolve`], but panics if `symbol` can't be resolved. The evaluator never uses this;
+/// it is for callers that know the Var exists.
+pub fn resolve_or_panic(env: PtrEnvironment, symbol: &Symbol) -> PtrVar {
+    try_resolve(env, symbol).unwrap_or_else(|err| panic!("{}", err))
+}
+
 #[derive(Debug, Clone)]
 pub enum ResolveError {
     NoSuchNamespace(SymbolUnqualified),
@@ -461,6 +1471,17 @@ pub enum ResolveError {
     UnknownCurrentNamespace,
 }
 
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchNamespace(ns_sym) => write!(f, "could not find namespace: {}", ns_sym),
+            Self::NoSuchVar(var_sym) => write!(f, "could not resolve var: {}", var_sym),
+            Self::UnboundVar(var_sym) => write!(f, "attempted to deref unbound Var: {}", var_sym),
+            Self::UnknownCurrentNamespace => write!(f, "could not determine current namespace"),
+        }
+    }
+}
+
 impl From<GetVarError> for ResolveError {
     fn from(get_var_err: GetVarError) -> Self {
         match get_var_err {
@@ -477,3 +1498,932 @@ impl From<GetValueError> for ResolveError {
         }
     }
 }
+
+/// An error raised while evaluating a form.
+///
+/// Carries the offending form, a category and a human-readable message. Categories are the
+/// unqualified keywords used for `:jinme.anomalies/category` in [`AnomalyMap`]:
+/// - `:incorrect` - A malformed special form, a bad binding, or a wrong argument count
+/// - `:not-found` - A symbol that can't be resolved or a Var that is unbound
+/// - `:unsupported` - An operation the value doesn't support, e.g. applying a non-function
+///
+/// Errors raised by `throw` take their category and message from the thrown `ex-info` map.
+///
+/// # Example
+///
+/// ```
+/// # use jinme::prelude::*;
+/// let err = EvalError::incorrect(Value::nil_ptr(), "bad form".to_owned());
+/// let anomaly = err.to_anomaly_map();
+/// assert_eq!(anomaly.get_category(), KeywordUnqualified::new("incorrect"));
+/// assert_eq!(anomaly.get_message(), "bad form");
+/// ```
+#[derive(Debug, Clone)]
+pub struct EvalError {
+    form: PtrValue,
+    category: KeywordUnqualified,
+    message: String,
+    /// The `ex-info` map passed to `throw`, if this error was thrown by a program
+    thrown: Option<AnomalyMap>,
+    /// Where the innermost failing form with a known position was read from
+    position: Option<Box<read2::SourcePosition>>,
+}
+
+impl EvalError {
+    pub fn new(form: PtrValue, category: KeywordUnqualified, message: String) -> Self {
+        Self {
+            form,
+            category,
+            message,
+            thrown: None,
+            position: None,
+        }
+    }
+
+    /// Creates an error for an `ex-info` map thrown by `form`, taking its category and message
+    /// from the map.
+    pub fn thrown(form: PtrValue, anomaly: AnomalyMap) -> Self {
+        Self {
+            form,
+            category: anomaly.get_category(),
+            message: anomaly.get_message(),
+            thrown: Some(anomaly),
+            position: None,
+        }
+    }
+
+    pub fn incorrect(form: PtrValue, message: String) -> Self {
+        Self::new(form, KeywordUnqualified::new("incorrect"), message)
+    }
+
+    pub fn not_found(form: PtrValue, message: String) -> Self {
+        Self::new(form, KeywordUnqualified::new("not-found"), message)
+    }
+
+    pub fn unsupported(form: PtrValue, message: String) -> Self {
+        Self::new(form, KeywordUnqualified::new("unsupported"), message)
+    }
+
+    pub fn form(&self) -> &PtrValue {
+        &self.form
+    }
+
+    pub fn category(&self) -> &KeywordUnqualified {
+        &self.category
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn is_thrown(&self) -> bool {
+        self.thrown.is_some()
+    }
+
+    /// Where the form that failed was read from, if the reader recorded it. This is the
+    /// innermost enclosing form with a position, e.g. the call `(f x)` when `x` is unbound.
+    pub fn position(&self) -> Option<&read2::SourcePosition> {
+        self.position.as_deref()
+    }
+
+    /// Records the position of `form` unless a form nested inside it already has.
+    fn or_position_of(mut self, form: &Value) -> Self {
+        if self.position.is_none() {
+            self.position = read2::SourcePosition::from_value(form).map(Box::new);
+        }
+        self
+    }
+
+    /// Returns the category and message as an [`AnomalyMap`], with the offending form under
+    /// `:jinme.anomalies/form` and its [position](Self::position), if known, under `:line`,
+    /// `:column` and so on. A thrown error returns the `ex-info` map that was thrown, as-is.
+    pub fn to_anomaly_map(&self) -> AnomalyMap {
+        if let Some(thrown) = &self.thrown {
+            return thrown.clone();
+        }
+        let mut anomaly = AnomalyMap::new(self.category.clone(), self.message.clone());
+        anomaly.insert(
+            Value::keyword_qualified_ptr("jinme.anomalies", "form"),
+            self.form.clone(),
+        );
+        if let Some(meta) = self.position.as_ref().and_then(|p| p.assoc_to_meta(None)) {
+            anomaly.merge_in(&meta);
+        }
+        anomaly
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An environment with `clojure.core` as the current namespace and only the builtins the
+    /// special form tests call: `+` on integers, `get`, `list`, `ex-info` and `ex-message`
+    fn create_env() -> PtrEnvironment {
+        let mut env_builder = Environment::builder();
+        env_builder.set_current_namespace_var("clojure.core", "*ns*");
+        let env = env_builder.build_ptr();
+        let clojure_core = Namespace::new_empty_ptr("clojure.core");
+        clojure_core.bind_value("*ns*", Value::handle(Handle::new(clojure_core.clone())));
+        env.insert_namespace(clojure_core.clone());
+
+        clojure_core.build_and_bind_function(
+            "+",
+            vec![closure_fn(FunctionArity::AtLeast(0), |_env, _ctx, args| {
+                let ints = args
+                    .iter()
+                    .map(|arg| arg.preview_integer().expect("+ takes integers"));
+                Value::integer_ptr(ints.sum())
+            })],
+        );
+        clojure_core.build_and_bind_function(
+            "get",
+            vec![closure_fn(FunctionArity::AtLeast(2), |_env, _ctx, args| {
+                let found = args[0].preview_map_ref().and_then(|map| map.get(&args[1]));
+                found
+                    .or_else(|| args.get(2).cloned())
+                    .unwrap_or_else(Value::nil_ptr)
+            })],
+        );
+        clojure_core.build_and_bind_function(
+            "list",
+            vec![closure_fn(FunctionArity::AtLeast(0), |_env, _ctx, args| {
+                Value::new_list_ptr(args)
+            })],
+        );
+        clojure_core.build_and_bind_function(
+            "ex-info",
+            vec![closure_fn(FunctionArity::Exactly(2), |_env, _ctx, args| {
+                let message = value_optics::preview_string(&args[0]).expect("message string");
+                let mut anomaly = AnomalyMap::new(KeywordUnqualified::new("fault"), message);
+                anomaly.merge_in(args[1].preview_map_ref().expect("data map"));
+                Value::map_ptr(anomaly.into_inner())
+            })],
+        );
+        clojure_core.build_and_bind_function(
+            "ex-message",
+            vec![closure_fn(FunctionArity::Exactly(1), |_env, _ctx, args| {
+                let anomaly = preview_ex_info(&args[0]).expect("an ex-info map");
+                Value::string_ptr(anomaly.get_message())
+            })],
+        );
+        env
+    }
+
+    /// Reads and evaluates each form of `input` in `env`, returning the value of the last one
+    fn try_eval_in(env: PtrEnvironment, input: &str) -> Result<PtrValue, EvalError> {
+        let mut value = Value::nil_ptr();
+        let mut rest = input;
+        while let (next, Some(form)) = read(env.clone(), rest).expect("failed to read") {
+            value = try_eval(env.clone(), EvalContext::new_empty(), form)?;
+            rest = next;
+        }
+        Ok(value)
+    }
+
+    /// Reads and evaluates each form of `input` in a fresh environment, returning the value of
+    /// the last one
+    fn eval_str(input: &str) -> PtrValue {
+        try_eval_in(create_env(), input).unwrap_or_else(|err| panic!("{input}: {err}"))
+    }
+
+    fn eval_err(input: &str) -> EvalError {
+        match try_eval_in(create_env(), input) {
+            Ok(value) => panic!("{input}: expected an error, got: {value}"),
+            Err(err) => err,
+        }
+    }
+
+    fn read_one(input: &str) -> PtrValue {
+        read(create_env(), input)
+            .expect("failed to read")
+            .1
+            .expect("no value read")
+    }
+
+    /// Asserts that evaluating `input` fails as `:incorrect` with `message`
+    fn assert_incorrect(input: &str, message: &str) {
+        let err = eval_err(input);
+        assert_eq!(
+            err.category(),
+            &KeywordUnqualified::new("incorrect"),
+            "{input}"
+        );
+        assert_eq!(err.message(), message, "{input}");
+    }
+
+    fn function_name(value: &PtrValue) -> Option<String> {
+        let function = value_optics::preview_function(value)
+            .unwrap_or_else(|| panic!("expected a function, got: {value}"));
+        function.name().map(str::to_owned)
+    }
+
+    // if
+
+    #[test]
+    fn if_truthy_test_evaluates_then_branch() {
+        for test in ["true", "0", "[]"] {
+            assert_eq!(
+                eval_str(&format!("(if {test} :then :else)")),
+                read_one(":then")
+            );
+        }
+    }
+
+    #[test]
+    fn if_nil_and_false_are_falsey() {
+        assert_eq!(eval_str("(if nil :then :else)"), read_one(":else"));
+        assert_eq!(eval_str("(if false :then :else)"), read_one(":else"));
+        assert!(eval_str("(if false :then)").is_nil());
+    }
+
+    #[test]
+    fn if_does_not_evaluate_untaken_branch() {
+        // `no-such-var` fails to resolve if it is evaluated
+        assert_eq!(eval_str("(if true :then no-such-var)"), read_one(":then"));
+        assert_eq!(eval_str("(if false no-such-var :else)"), read_one(":else"));
+    }
+
+    #[test]
+    fn if_errors() {
+        for input in ["(if)", "(if true 1 2 3)"] {
+            let err = eval_err(input);
+            assert_eq!(err.category(), &KeywordUnqualified::new("incorrect"));
+            assert!(err.message().starts_with("if: expected a test"), "{input}");
+        }
+        assert_eq!(
+            eval_err("(if no-such-var 1 2)").category(),
+            &KeywordUnqualified::new("not-found")
+        );
+    }
+
+    // def
+
+    #[test]
+    fn def_interns_and_binds_var_in_current_namespace() {
+        let env = create_env();
+        let result = try_eval_in(env.clone(), "(def answer 42)").unwrap();
+        let var = value_optics::preview_var(&result).expect("def should return a Var");
+        assert_eq!(var.deref(), Some(Value::integer_ptr(42)));
+        let current_ns = env.get_current_namespace_or_panic();
+        assert_eq!(
+            current_ns.get_value_or_panic("answer"),
+            Value::integer_ptr(42)
+        );
+        assert_eq!(try_eval_in(env, "answer").unwrap(), Value::integer_ptr(42));
+    }
+
+    #[test]
+    fn def_without_init_creates_unbound_var() {
+        let var = value_optics::preview_var(&eval_str("(def pending)")).unwrap();
+        assert!(var.is_unbound());
+    }
+
+    #[test]
+    fn def_sets_var_meta_from_docstring_and_name() {
+        let var = value_optics::preview_var(&eval_str("(def ^:private documented \"the docs\" 1)"))
+            .unwrap();
+        assert_eq!(
+            var.get_meta(&Value::keyword_unqualified_ptr("doc")),
+            Some(Value::string_ptr("the docs".to_owned()))
+        );
+        assert_eq!(
+            var.get_meta(&Value::keyword_unqualified_ptr("private")),
+            Some(Value::boolean_ptr(true))
+        );
+    }
+
+    #[test]
+    fn def_errors() {
+        assert_incorrect("(def 1 2)", "def: first argument must be a symbol, got: 1");
+        assert_incorrect("(def x 1 2)", "def: docstring must be a string, got: 1");
+        assert_incorrect(
+            "(def other/x 1)",
+            "def: cannot def other/x from namespace clojure.core",
+        );
+        for input in ["(def)", "(def x \"doc\" 1 2)"] {
+            let err = eval_err(input);
+            assert!(err.message().starts_with("def: expected a name"), "{input}");
+        }
+    }
+
+    // quote
+
+    #[test]
+    fn quote_returns_form_unevaluated() {
+        assert_eq!(
+            eval_str("(quote foo)"),
+            Value::symbol_unqualified_ptr("foo")
+        );
+        assert_eq!(eval_str("'(a b c)"), read_one("(a b c)"));
+        assert_eq!(read_one("'foo"), read_one("(quote foo)"));
+    }
+
+    #[test]
+    fn quote_errors() {
+        assert_incorrect("(quote)", "quote: expected exactly 1 argument, got 0");
+        assert_incorrect("(quote a b)", "quote: expected exactly 1 argument, got 2");
+    }
+
+    // var
+
+    #[test]
+    fn var_returns_the_var_without_dereferencing_it() {
+        let env = create_env();
+        try_eval_in(env.clone(), "(def x 1)").unwrap();
+        let var = try_eval_in(env.clone(), "(var x)").unwrap();
+        let var = value_optics::preview_var(&var).expect("var should return a Var");
+        assert_eq!(var.deref(), Some(Value::integer_ptr(1)));
+        // locals don't shadow the var
+        assert_eq!(
+            try_eval_in(env.clone(), "(let* [x 2] (var x))").unwrap(),
+            Value::var_ptr(var)
+        );
+        assert!(
+            try_eval_in(env, "(var clojure.core/list)")
+                .unwrap()
+                .is_var()
+        );
+    }
+
+    #[test]
+    fn var_errors() {
+        assert_incorrect("(var)", "var: expected exactly 1 argument, got 0");
+        assert_incorrect("(var 1)", "var: expected a symbol, got 1");
+        assert_eq!(
+            eval_err("(var no-such-var)").category(),
+            &KeywordUnqualified::new("not-found")
+        );
+    }
+
+    // let* and do
+
+    #[test]
+    fn let_star_errors() {
+        assert_incorrect(
+            "(let* x 1)",
+            "let*: first argument must be a binding vector",
+        );
+        assert_incorrect(
+            "(let* [a] a)",
+            "let*: binding vector must have an even number of elements",
+        );
+    }
+
+    #[test]
+    fn do_returns_its_last_value() {
+        assert!(eval_str("(do)").is_nil());
+        assert_eq!(eval_str("(do (def x 1) (+ x 1))"), Value::integer_ptr(2));
+        assert_eq!(
+            eval_err("(do 1 no-such-var 2)").category(),
+            &KeywordUnqualified::new("not-found")
+        );
+    }
+
+    // loop* and recur
+    // `(get {0 false} n true)` is falsey only when n is 0
+
+    #[test]
+    fn loop_star_binds_like_let_star() {
+        assert_eq!(eval_str("(loop* [a 1 b (+ a 1)] b)"), Value::integer_ptr(2));
+    }
+
+    #[test]
+    fn loop_star_recur_rebinds_and_reruns_body() {
+        assert_eq!(
+            eval_str(
+                "(loop* [n 5 acc 0] (if (get {0 false} n true) (recur (+ n -1) (+ acc n)) acc))"
+            ),
+            Value::integer_ptr(15)
+        );
+    }
+
+    #[test]
+    fn deep_loop_star_recur_runs_in_constant_stack() {
+        // recur reaches the loop through do, let*, letfn* and if in tail position
+        assert_eq!(
+            eval_str(
+                "(loop* [n 20000 acc 0]
+                   (if (get {0 false} n true)
+                     (do (let* [m (+ n -1)] (letfn* [] (recur m (+ acc 1)))))
+                     acc))"
+            ),
+            Value::integer_ptr(20000)
+        );
+    }
+
+    #[test]
+    fn deep_fn_star_recur_runs_in_constant_stack() {
+        assert_eq!(
+            eval_str(
+                "((fn* [n acc] (if (get {0 false} n true) (recur (+ n -1) (+ acc 1)) acc)) 20000 0)"
+            ),
+            Value::integer_ptr(20000)
+        );
+    }
+
+    #[test]
+    fn deep_destructuring_loop_star_recur_runs_in_constant_stack() {
+        assert_eq!(
+            eval_str(
+                "(loop* [[n acc] [20000 0]] (if (get {0 false} n true) (recur [(+ n -1) (+ acc 1)]) acc))"
+            ),
+            Value::integer_ptr(20000)
+        );
+    }
+
+    #[test]
+    fn recur_targets_the_innermost_loop() {
+        assert_eq!(
+            eval_str(
+                "(loop* [outer 3 acc 0]
+                   (if (get {0 false} outer true)
+                     (recur (+ outer -1) (loop* [inner 2 acc acc] (if (get {0 false} inner true) (recur (+ inner -1) (+ acc 1)) acc)))
+                     acc))"
+            ),
+            Value::integer_ptr(6)
+        );
+    }
+
+    #[test]
+    fn fn_star_recur_passes_variadic_parameter_as_is() {
+        assert_eq!(
+            eval_str(
+                "((fn* [n & more] (if (get {0 false} n true) (recur (+ n -1) more) more)) 3 :a :b)"
+            ),
+            read_one("(:a :b)")
+        );
+    }
+
+    #[test]
+    fn loop_star_errors() {
+        assert_incorrect(
+            "(loop* x 1)",
+            "loop*: first argument must be a binding vector",
+        );
+        assert_incorrect(
+            "(loop* [a] a)",
+            "loop*: binding vector must have an even number of elements",
+        );
+    }
+
+    #[test]
+    fn recur_errors() {
+        let not_in_tail = "recur: can only be used in tail position of loop* or fn*";
+        assert_incorrect("(loop* [n 1] (+ 1 (recur n)))", not_in_tail);
+        assert_incorrect("(recur 1)", not_in_tail);
+        assert_incorrect("(loop* [n 1] (let* [m (recur n)] m))", not_in_tail);
+        assert_incorrect("(loop* [n 1] (try (recur n)))", not_in_tail);
+        assert_incorrect(
+            "(loop* [a 1 b 2] (recur 1))",
+            "recur: expected 2 argument(s) to match loop* bindings, got 1",
+        );
+        assert_incorrect(
+            "((fn* [a] (recur 1 2)) 0)",
+            "recur: expected 1 argument(s) to match fn* parameters, got 2",
+        );
+    }
+
+    // try_eval
+
+    #[test]
+    fn try_eval_unresolved_symbol_is_not_found() {
+        let err = eval_err("(+ 1 no-such-var)");
+        assert_eq!(err.category(), &KeywordUnqualified::new("not-found"));
+        assert_eq!(err.form(), &Value::symbol_unqualified_ptr("no-such-var"));
+        assert_eq!(
+            err.message(),
+            "could not resolve var: clojure.core/no-such-var"
+        );
+    }
+
+    #[test]
+    fn try_eval_malformed_special_form_points_at_the_form() {
+        let err = eval_err("(let* [a] a)");
+        assert_eq!(err.form(), &read_one("(let* [a] a)"));
+    }
+
+    #[test]
+    fn try_eval_wrong_argument_count_is_incorrect() {
+        let err = eval_err("((fn* [a b] a) 1)");
+        assert_eq!(err.category(), &KeywordUnqualified::new("incorrect"));
+        assert!(err.message().contains("unsupported argument count of 1"));
+    }
+
+    #[test]
+    fn try_eval_applying_non_function_is_unsupported() {
+        let err = eval_err("(1 2 3)");
+        assert_eq!(err.category(), &KeywordUnqualified::new("unsupported"));
+        assert_eq!(err.form(), &Value::integer_ptr(1));
+    }
+
+    #[test]
+    fn try_eval_propagates_errors_from_fn_star_bodies() {
+        let err = eval_err("(def broken (fn* [] (if))) (broken)");
+        assert_eq!(err.category(), &KeywordUnqualified::new("incorrect"));
+        assert!(err.message().starts_with("if: "));
+    }
+
+    #[test]
+    fn eval_error_converts_to_anomaly_map() {
+        let anomaly = eval_err("no-such-var").to_anomaly_map();
+        assert_eq!(anomaly.get_category(), KeywordUnqualified::new("not-found"));
+        assert_eq!(
+            anomaly.get_message(),
+            "could not resolve var: clojure.core/no-such-var"
+        );
+        assert_eq!(
+            anomaly
+                .inner()
+                .get(&Value::keyword_qualified_ptr("jinme.anomalies", "form")),
+            Some(Value::symbol_unqualified_ptr("no-such-var"))
+        );
+    }
+
+    // throw, try, catch and finally
+
+    #[test]
+    fn throw_without_catch_is_eval_error() {
+        let err = eval_err("(throw (ex-info \"boom\" {:jinme.anomalies/category :conflict}))");
+        assert!(err.is_thrown());
+        assert_eq!(err.category(), &KeywordUnqualified::new("conflict"));
+        assert_eq!(err.message(), "boom");
+    }
+
+    #[test]
+    fn throw_errors() {
+        assert_incorrect("(throw)", "throw: expected exactly 1 argument, got 0");
+        let err = eval_err("(throw :oops)");
+        assert!(!err.is_thrown());
+        assert_eq!(
+            err.message(),
+            "throw: expected a map with :jinme.anomalies/category and :jinme.anomalies/message, got: :oops"
+        );
+    }
+
+    #[test]
+    fn try_without_error_returns_body_value() {
+        assert_eq!(
+            eval_str("(try 1 2 (catch :fault e 3))"),
+            Value::integer_ptr(2)
+        );
+        assert!(eval_str("(try)").is_nil());
+    }
+
+    #[test]
+    fn catch_matches_on_category() {
+        assert_eq!(
+            eval_str(
+                "(try (throw (ex-info \"boom\" {:answer 42})) (catch :conflict e :wrong) (catch :fault e (get e :answer)))"
+            ),
+            Value::integer_ptr(42)
+        );
+    }
+
+    #[test]
+    fn catch_matches_on_predicate() {
+        assert_eq!(
+            eval_str(
+                "(try (throw (ex-info \"boom\" {:answer 42})) (catch (fn* [e] (get e :answer)) e (ex-message e)))"
+            ),
+            Value::string_ptr("boom".to_owned())
+        );
+    }
+
+    #[test]
+    fn catch_handles_evaluator_errors() {
+        assert_eq!(
+            eval_str("(try no-such-var (catch :not-found e (ex-message e)))"),
+            Value::string_ptr("could not resolve var: clojure.core/no-such-var".to_owned())
+        );
+    }
+
+    #[test]
+    fn unmatched_catch_rethrows() {
+        for matcher in [":conflict", ":my/fault", "(fn* [e] false)"] {
+            let err = eval_err(&format!(
+                "(try (throw (ex-info \"boom\" {{}})) (catch {matcher} e :caught))"
+            ));
+            assert_eq!(
+                err.category(),
+                &KeywordUnqualified::new("fault"),
+                "catching with {matcher}"
+            );
+        }
+    }
+
+    #[test]
+    fn failing_matchers_do_not_hide_the_error() {
+        for matcher in ["(fn* [e] (no-such-fn e))", "(get {} :missing)"] {
+            let err = eval_err(&format!(
+                "(try (throw (ex-info \"boom\" {{}})) (catch {matcher} e :caught))"
+            ));
+            assert_eq!(err.message(), "boom", "catching with {matcher}");
+        }
+    }
+
+    #[test]
+    fn errors_in_catch_bodies_propagate() {
+        let err = eval_err("(try (throw (ex-info \"boom\" {})) (catch :fault e no-such-var))");
+        assert_eq!(err.category(), &KeywordUnqualified::new("not-found"));
+    }
+
+    #[test]
+    fn finally_runs_after_success_and_failure() {
+        let env = create_env();
+        let ok = try_eval_in(env.clone(), "(try :ok (finally (def after-success :ran)))");
+        assert_eq!(ok.unwrap(), read_one(":ok"));
+        assert_eq!(
+            try_eval_in(env.clone(), "after-success").unwrap(),
+            read_one(":ran")
+        );
+
+        let err = try_eval_in(
+            env.clone(),
+            "(try (throw (ex-info \"boom\" {})) (finally (def after-failure :ran)))",
+        );
+        assert_eq!(err.unwrap_err().message(), "boom");
+        assert_eq!(try_eval_in(env, "after-failure").unwrap(), read_one(":ran"));
+    }
+
+    #[test]
+    fn try_errors() {
+        assert_incorrect(
+            "(try (finally 1) 2)",
+            "try: body expressions must precede catch and finally clauses",
+        );
+        assert_incorrect(
+            "(try 1 (finally 2) (catch :fault e 3))",
+            "try: finally clause must be last",
+        );
+        assert_incorrect(
+            "(try 1 (finally 2) (finally 3))",
+            "try: only one finally clause is allowed",
+        );
+        assert_incorrect(
+            "(try 1 (catch :fault))",
+            "catch: expected a matcher and a binding name",
+        );
+        assert_incorrect(
+            "(try 1 (catch :fault 2 3))",
+            "catch: binding name must be a symbol, got: 2",
+        );
+        assert_incorrect(
+            "(try :ok (catch \"x\" e :caught))",
+            "catch: matcher must be a keyword or a function, got: \"x\"",
+        );
+        // an error in finally wins over the body's value
+        assert_eq!(
+            eval_err("(try 1 (finally no-such-var))").category(),
+            &KeywordUnqualified::new("not-found")
+        );
+    }
+
+    // destructuring
+
+    #[test]
+    fn sequential_destructuring() {
+        assert_eq!(
+            eval_str("(let* [[a b & rest :as all] [1 2 3 4]] [a b rest all])"),
+            read_one("[1 2 (3 4) [1 2 3 4]]")
+        );
+        assert_eq!(
+            eval_str("(let* [[a b c] '(1 2)] [a b c])"),
+            read_one("[1 2 nil]")
+        );
+        assert!(eval_str("(let* [[a & rest] [1]] rest)").is_nil());
+        assert_eq!(eval_str("(let* [[a b] nil] [a b])"), read_one("[nil nil]"));
+    }
+
+    #[test]
+    fn associative_destructuring() {
+        assert_eq!(
+            eval_str("(let* [{:keys [a b] c :c :as m} {:a 1 :b 2 :c 3}] [a b c m])"),
+            read_one("[1 2 3 {:a 1 :b 2 :c 3}]")
+        );
+        assert_eq!(
+            eval_str("(let* [{:strs [a] :syms [b]} {\"a\" 1 'b 2}] [a b])"),
+            read_one("[1 2]")
+        );
+        assert_eq!(
+            eval_str(
+                "(let* [{:keys [user/id] :user/keys [name]} {:user/id 7 :user/name \"x\"}] [id name])"
+            ),
+            read_one("[7 \"x\"]")
+        );
+    }
+
+    #[test]
+    fn associative_destructuring_defaults() {
+        assert_eq!(
+            eval_str("(let* [{:keys [a b] :or {b (+ 1 1)}} {:a 1}] [a b])"),
+            read_one("[1 2]")
+        );
+        // defaults are not evaluated when the key is present
+        assert_eq!(
+            eval_str("(let* [{:keys [a] :or {a (throw (ex-info \"boom\" {}))}} {:a 1}] a)"),
+            Value::integer_ptr(1)
+        );
+        assert_eq!(
+            eval_str("(let* [{:keys [a] :or {a 5}} nil] a)"),
+            Value::integer_ptr(5)
+        );
+    }
+
+    #[test]
+    fn nested_destructuring() {
+        assert_eq!(
+            eval_str(
+                "(let* [{[x y] :point {:keys [r]} :style} {:point [1 2] :style {:r 3}}] [x y r])"
+            ),
+            read_one("[1 2 3]")
+        );
+        assert_eq!(
+            eval_str("(let* [[{:keys [a]} [b]] [{:a 1} [2]]] [a b])"),
+            read_one("[1 2]")
+        );
+    }
+
+    #[test]
+    fn fn_star_parameter_destructuring() {
+        assert_eq!(
+            eval_str("((fn* [[a b] {:keys [c]}] (+ a b c)) [1 2] {:c 3})"),
+            Value::integer_ptr(6)
+        );
+        assert_eq!(
+            eval_str("((fn* [x & {:keys [y] :or {y 10}}] (+ x y)) 1 :y 2)"),
+            Value::integer_ptr(3)
+        );
+        assert_eq!(
+            eval_str("((fn* [x & {:keys [y] :or {y 10}}] (+ x y)) 1)"),
+            Value::integer_ptr(11)
+        );
+    }
+
+    #[test]
+    fn destructuring_errors() {
+        assert_incorrect("(let* [[a b] 1] a)", "cannot destructure 1 as a sequence");
+        assert_incorrect("(let* [{:keys [a]} 1] a)", "cannot destructure 1 as a map");
+        assert_incorrect(
+            "(let* [{:keys [a]} [1]] a)",
+            "cannot destructure [1] as a map, it has an odd number of elements",
+        );
+        assert_incorrect(
+            "(let* [1 2] 3)",
+            "binding pattern must be an unqualified symbol, a vector or a map, got: 1",
+        );
+        assert_incorrect(
+            "(let* [[a &] [1]] a)",
+            "& must be followed by a binding pattern",
+        );
+        assert_incorrect(
+            "(let* [[a :as] [1]] a)",
+            ":as must be followed by a binding pattern",
+        );
+        assert_incorrect("(let* [{:keys a} {}] a)", ":keys must be a vector, got: a");
+        assert_incorrect(
+            "(let* [{:keys [1]} {}] 1)",
+            ":keys names must be symbols, got: 1",
+        );
+        assert_incorrect(
+            "(let* [{:keys [a] :or 1} {}] a)",
+            ":or must be a map, got: 1",
+        );
+    }
+
+    // fn* and letfn*
+
+    #[test]
+    fn named_fn_star_can_call_itself() {
+        assert_eq!(
+            eval_str(
+                "((fn* count-down [n acc] (if (get {0 false} n true) (count-down (+ n -1) (+ acc 1)) acc)) 5 0)"
+            ),
+            Value::integer_ptr(5)
+        );
+        assert_eq!(
+            eval_str("(def self-fn (fn* me [] me)) (get {(self-fn) :same} self-fn)"),
+            read_one(":same")
+        );
+    }
+
+    #[test]
+    fn named_fn_star_sets_function_name() {
+        assert_eq!(
+            function_name(&eval_str("(fn* my-fn [x] x)")),
+            Some("my-fn".to_owned())
+        );
+        assert_eq!(
+            function_name(&eval_str("(fn* multi ([] 0) ([x] (multi)))")),
+            Some("multi".to_owned())
+        );
+        assert_eq!(function_name(&eval_str("(fn* [x] x)")), None);
+    }
+
+    #[test]
+    fn named_fn_star_name_shadows_outer_binding() {
+        assert_eq!(
+            eval_str("(let* [f 1 g (fn* f [] f)] (get {f :outer} 1 :shadowed))"),
+            read_one(":outer")
+        );
+        assert_eq!(
+            eval_str("(let* [f 1 g (fn* f [] f)] (get {(g) :fn} g))"),
+            read_one(":fn")
+        );
+    }
+
+    #[test]
+    fn fn_star_errors() {
+        assert_incorrect("(fn*)", "fn*: missing parameters");
+        assert_incorrect("(fn* f)", "fn*: missing parameters");
+        assert_incorrect(
+            "(fn* 1)",
+            "fn*: first argument must be a parameter vector or list of parameter vectors",
+        );
+        assert_incorrect(
+            "(fn* a/b [x] x)",
+            "fn*: name must be an unqualified symbol, got: a/b",
+        );
+        assert_incorrect(
+            "(fn* ([x] x) [y] y)",
+            "fn*: multi-arity forms must be lists",
+        );
+        assert_incorrect(
+            "(fn* ((x) x))",
+            "fn*: multi-arity form parameters must be a vector",
+        );
+        assert_incorrect("(fn* [&] 1)", "fn*: & must be followed by a parameter");
+        assert_incorrect(
+            "(fn* [& a b] 1)",
+            "fn*: unexpected parameter after variadic parameter: b",
+        );
+    }
+
+    #[test]
+    fn letfn_star_mutual_recursion() {
+        assert_eq!(
+            eval_str(
+                "(letfn* [my-even (fn* my-even [n] (if (get {0 false} n true) (my-odd (+ n -1)) true))
+                          my-odd (fn* my-odd [n] (if (get {0 false} n true) (my-even (+ n -1)) false))]
+                   [(my-even 10) (my-odd 7) (my-even 3)])"
+            ),
+            read_one("[true true false]")
+        );
+    }
+
+    #[test]
+    fn letfn_star_functions_outlive_their_siblings() {
+        let is_even = eval_str(
+            "(def only-even (letfn* [is-even (fn* is-even [n] (if (get {0 false} n true) (is-odd (+ n -1)) true))
+                                     is-odd (fn* is-odd [n] (if (get {0 false} n true) (is-even (+ n -1)) false))]
+                              is-even))
+             only-even",
+        );
+        assert_eq!(function_name(&is_even), Some("is-even".to_owned()));
+        let env = create_env();
+        env.get_namespace_or_panic("clojure.core")
+            .insert_var("f", Var::new_bound(Value::clone(&is_even)));
+        assert_eq!(try_eval_in(env, "(f 4)").unwrap(), Value::boolean_ptr(true));
+    }
+
+    #[test]
+    fn letfn_star_functions_see_the_same_siblings_on_every_call() {
+        let siblings = eval_str("(letfn* [a (fn* a [] b) b (fn* b [] a)] (list (a) (a) b))");
+        let functions: Vec<PtrFunction> = siblings
+            .preview_list()
+            .expect("expected a list")
+            .iter()
+            .map(|f| value_optics::preview_function(f).expect("expected a function"))
+            .collect();
+        assert!(Arc::ptr_eq(&functions[0], &functions[1]));
+        assert!(Arc::ptr_eq(&functions[0], &functions[2]));
+    }
+
+    #[test]
+    fn letfn_star_errors() {
+        assert_incorrect(
+            "(letfn* f 1)",
+            "letfn*: first argument must be a binding vector",
+        );
+        assert_incorrect(
+            "(letfn* [f] 1)",
+            "letfn*: binding vector must have an even number of elements",
+        );
+        assert_incorrect(
+            "(letfn* [a/f (fn* [] 1)] 1)",
+            "letfn*: binding name must be an unqualified symbol, got: a/f",
+        );
+        assert_incorrect(
+            "(letfn* [f 1] f)",
+            "letfn*: binding init must be a fn* form, got: 1",
+        );
+    }
+}
@@ -74,6 +74,13 @@ impl EvalContext {
         self.locals.get(name).cloned()
     }
 
+    /// Iterate over the local bindings, in no particular order.
+    pub fn iter_locals(&self) -> impl Iterator<Item = (&str, &PtrValue)> {
+        self.locals
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
     /// Check if a local binding exists.
     pub fn has_local(&self, name: &str) -> bool {
         self.locals.contains_key(name)
@@ -101,6 +108,19 @@ mod tests {
         assert_eq!(ctx.len(), 0);
     }
 
+    #[test]
+    fn iter_locals() {
+        let ctx = EvalContext::new_empty()
+            .with_local("x", Value::integer_ptr(1))
+            .with_local("y", Value::integer_ptr(2));
+        let mut locals: Vec<(&str, &PtrValue)> = ctx.iter_locals().collect();
+        locals.sort_by_key(|(name, _)| *name);
+        assert_eq!(
+            locals,
+            vec![("x", &Value::integer_ptr(1)), ("y", &Value::integer_ptr(2))]
+        );
+    }
+
     #[test]
     fn with_local() {
         let ctx = EvalContext::new_empty();
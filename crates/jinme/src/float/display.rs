@@ -3,7 +3,15 @@ use ::std::fmt;
 
 impl fmt::Display for Float {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let formatted = format!("{}", f64::from(self));
+        let float = f64::from(self);
+        // the symbolic values print as the reader reads them
+        if float.is_nan() {
+            return write!(f, "##NaN");
+        }
+        if float.is_infinite() {
+            return write!(f, "{}", if float > 0.0 { "##Inf" } else { "##-Inf" });
+        }
+        let formatted = format!("{}", float);
         write!(
             f,
             "{}",
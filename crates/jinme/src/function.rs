@@ -96,8 +96,20 @@ pub fn build_function_value_ptr(
 /// # Returns
 ///
 /// The result of the function invocation as a `PtrValue`
+///
+/// Implementations that can fail without panicking override `try_invoke`, which by default
+/// wraps `invoke` in `Ok`.
 pub trait IFunction: Send + Sync {
     fn invoke(&self, env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>) -> PtrValue;
+
+    fn try_invoke(
+        &self,
+        env: PtrEnvironment,
+        ctx: EvalContext,
+        args: Vec<PtrValue>,
+    ) -> Result<PtrValue, EvalError> {
+        Ok(self.invoke(env, ctx, args))
+    }
 }
 
 /// Function implementation for closures.
@@ -143,14 +155,30 @@ pub struct Function {
 impl IFunction for Function {
     // #[tracing::instrument(ret, fields(self, env, args), level = "info")]
     fn invoke(&self, env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>) -> PtrValue {
-        let body = self.body_supporting_arg_count(args.len()).expect(&format!(
-            "function '{}' invoked with unsupported argument count of {}, expected one of {}; {}",
-            self.name.as_ref().unwrap_or(&String::from("<unnamed>")),
-            args.len(),
-            self.arity_strings().join(","),
-            Value::list_ptr(List::from(args.clone())),
-        ));
-        body.invoke(env, ctx, args)
+        self.try_invoke(env, ctx, args)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_invoke(
+        &self,
+        env: PtrEnvironment,
+        ctx: EvalContext,
+        args: Vec<PtrValue>,
+    ) -> Result<PtrValue, EvalError> {
+        let Some(body) = self.body_supporting_arg_count(args.len()) else {
+            let args_form = Value::list_ptr(List::from(args.clone()));
+            return Err(EvalError::incorrect(
+                args_form.clone(),
+                format!(
+                    "function '{}' invoked with unsupported argument count of {}, expected one of {}; {}",
+                    self.name.as_ref().unwrap_or(&String::from("<unnamed>")),
+                    args.len(),
+                    self.arity_strings().join(","),
+                    args_form,
+                ),
+            ));
+        };
+        body.try_invoke(env, ctx, args)
     }
 }
 
@@ -159,6 +187,15 @@ impl IFunction for PtrFunction {
     fn invoke(&self, env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>) -> PtrValue {
         self.as_ref().invoke(env, ctx, args)
     }
+
+    fn try_invoke(
+        &self,
+        env: PtrEnvironment,
+        ctx: EvalContext,
+        args: Vec<PtrValue>,
+    ) -> Result<PtrValue, EvalError> {
+        self.as_ref().try_invoke(env, ctx, args)
+    }
 }
 
 impl IHandle for Function {}
@@ -7,9 +7,11 @@ use ::std::{
 use as_any::{AsAny, Downcast};
 
 mod buf_read_handle;
+mod regex_handle;
 mod write_handle;
 
 pub use buf_read_handle::BufReadHandle;
+pub use regex_handle::{RegexHandle, RegexMatch, RegexMatcherHandle};
 pub use write_handle::WriteHandle;
 
 /// Trait for handle implementations that can be downcast to specific types.
@@ -180,6 +182,10 @@ impl fmt::Debug for Handle {
             write!(f, "#handle[jinme.BufReadHandle {:p}]", addr)
         } else if let Some(_) = self.downcast_ref::<WriteHandle>() {
             write!(f, "#handle[jinme.WriteHandle {:p}]", addr)
+        } else if let Some(regex) = self.downcast_ref::<RegexHandle>() {
+            write!(f, "{}", regex)
+        } else if self.downcast_ref::<RegexMatcherHandle>().is_some() {
+            write!(f, "#handle[jinme.RegexMatcherHandle {:p}]", addr)
         } else if let Some(func) = self.downcast_ref::<PtrFunction>() {
             write!(
                 f,
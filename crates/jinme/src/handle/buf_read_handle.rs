@@ -1,20 +1,64 @@
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    read2::{Reader, StreamReader},
+};
 use std::{
     io,
     sync::{Arc, Mutex},
 };
 
+/// The input a [`BufReadHandle`] reads from, buffering text pulled for forms but not read yet
+pub type BufReadStream = StreamReader<Box<dyn io::BufRead + Send + Sync>>;
+
 #[derive(Clone)]
-pub struct BufReadHandle(Arc<Mutex<dyn io::BufRead + Send + Sync>>);
+pub struct BufReadHandle(Arc<Mutex<BufReadStream>>);
 
 impl BufReadHandle {
-    pub fn new(writer: impl io::BufRead + Send + Sync + 'static) -> Self {
-        Self(Arc::new(Mutex::new(writer)))
+    pub fn new(reader: impl io::BufRead + Send + Sync + 'static) -> Self {
+        Self(Arc::new(Mutex::new(StreamReader::new(Box::new(reader)))))
     }
+
+    /// Create a handle that reads from `string`
+    pub fn new_string_reader(string: String) -> Self {
+        Self::new(io::Cursor::new(string))
+    }
+
     /// Get the inner Arc<Mutex> directly to avoid nested locks
-    pub fn inner(&self) -> std::sync::Arc<Mutex<dyn io::BufRead + Send + Sync>> {
+    pub fn inner(&self) -> std::sync::Arc<Mutex<BufReadStream>> {
         self.0.clone()
     }
+
+    /// Reads the next form with `reader`, or `None` if the input ends before one starts. See
+    /// [`StreamReader::read_form`].
+    pub fn read_form(
+        &self,
+        reader: &Reader,
+        env: PtrEnvironment,
+    ) -> Result<Option<PtrValue>, ReadError> {
+        self.0
+            .lock()
+            .expect("mutex poisoned while reading a form")
+            .read_form(reader, env)
+    }
+
+    /// Reads the next line, without its line ending, or `None` at the end of the input
+    pub fn read_line(&self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        let len = io::BufRead::read_line(
+            &mut *self.0.lock().expect("mutex poisoned while reading a line"),
+            &mut line,
+        )?;
+        if len == 0 {
+            return Ok(None);
+        }
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+        Ok(Some(line))
+    }
 }
 
 impl IHandle for BufReadHandle {}
@@ -0,0 +1,163 @@
+use crate::prelude::*;
+use std::fmt;
+
+/// A compiled regular expression, as read from a `#"..."` literal or made by `re-pattern`.
+///
+/// Like Clojure's patterns, two regexes are only equal if they are the same handle.
+#[derive(Clone)]
+pub struct RegexHandle {
+    regex: regex::Regex,
+    /// The same pattern, anchored to match only the whole input
+    whole: regex::Regex,
+}
+
+impl RegexHandle {
+    /// Compiles `pattern`, or says what is wrong with it
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let compile = |pattern: &str| {
+            regex::Regex::new(pattern).map_err(|err| {
+                // syntax errors quote the pattern and point at the problem above their last line
+                let message = err.to_string();
+                message
+                    .lines()
+                    .last()
+                    .unwrap_or_default()
+                    .trim_start_matches("error: ")
+                    .to_owned()
+            })
+        };
+        Ok(Self {
+            regex: compile(pattern)?,
+            whole: compile(&format!(r"\A(?:{pattern})\z"))?,
+        })
+    }
+
+    /// The pattern this regex was compiled from
+    pub fn as_str(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// The first match in `input` at or after byte offset `start`
+    pub fn find_at(&self, input: &str, start: usize) -> Option<RegexMatch> {
+        self.regex
+            .captures_at(input, start)
+            .map(|captures| RegexMatch::new(&captures))
+    }
+
+    /// The match of the whole of `input`, if it matches
+    pub fn matches(&self, input: &str) -> Option<RegexMatch> {
+        self.whole
+            .captures(input)
+            .map(|captures| RegexMatch::new(&captures))
+    }
+}
+
+impl IHandle for RegexHandle {}
+
+/// Prints as the `#"..."` literal that reads back as this regex
+impl fmt::Display for RegexHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#\"")?;
+        let mut chars = self.as_str().chars();
+        while let Some(c) = chars.next() {
+            match c {
+                // an escape is kept as-is, so an escaped `"` stays escaped
+                '\\' => {
+                    write!(f, "\\")?;
+                    if let Some(escaped) = chars.next() {
+                        write!(f, "{}", escaped)?;
+                    }
+                }
+                '"' => write!(f, "\\\"")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+/// A regex match: the matched text, the text of each group, and where the match is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegexMatch {
+    /// The whole match, then each group, `None` for groups that took no part in the match
+    groups: Vec<Option<String>>,
+    start: usize,
+    end: usize,
+}
+
+impl RegexMatch {
+    fn new(captures: &regex::Captures) -> Self {
+        let whole = captures.get_match();
+        Self {
+            groups: captures
+                .iter()
+                .map(|group| group.map(|group| group.as_str().to_owned()))
+                .collect(),
+            start: whole.start(),
+            end: whole.end(),
+        }
+    }
+
+    /// The match as Clojure gives it: the matched string if the regex has no groups, otherwise
+    /// a vector of it and each group, with nil for groups that took no part in the match
+    pub fn to_value(&self) -> PtrValue {
+        let string = |group: &Option<String>| {
+            group
+                .as_ref()
+                .map(|group| Value::string_ptr(group.to_owned()))
+                .unwrap_or_else(Value::nil_ptr)
+        };
+        match self.groups.as_slice() {
+            [whole] => string(whole),
+            groups => Value::new_vector_ptr(groups.iter().map(string).collect()),
+        }
+    }
+}
+
+/// A regex searching through an input, one match at a time, as made by `re-matcher`.
+#[derive(Clone)]
+pub struct RegexMatcherHandle {
+    regex: RegexHandle,
+    input: String,
+    /// Where to search from next, or `None` once the input is exhausted
+    position: Option<usize>,
+    last_match: Option<RegexMatch>,
+}
+
+impl RegexMatcherHandle {
+    pub fn new(regex: RegexHandle, input: String) -> Self {
+        Self {
+            regex,
+            input,
+            position: Some(0),
+            last_match: None,
+        }
+    }
+
+    /// The next match, which also becomes [`RegexMatcherHandle::last_match`]
+    pub fn find(&mut self) -> Option<RegexMatch> {
+        let found = self
+            .position
+            .and_then(|position| self.regex.find_at(&self.input, position));
+        self.position = found.as_ref().and_then(|found| {
+            if found.start < found.end {
+                Some(found.end)
+            } else {
+                // an empty match would be found again, so step over the next character
+                self.input[found.end..]
+                    .chars()
+                    .next()
+                    .map(|c| found.end + c.len_utf8())
+            }
+        });
+        self.last_match = found.clone();
+        found
+    }
+
+    /// The most recent match found by [`RegexMatcherHandle::find`], if it found one
+    pub fn last_match(&self) -> Option<&RegexMatch> {
+        self.last_match.as_ref()
+    }
+}
+
+impl IHandle for RegexMatcherHandle {}
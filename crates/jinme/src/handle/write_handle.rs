@@ -5,16 +5,62 @@ use std::{
 };
 
 #[derive(Clone)]
-pub struct WriteHandle(Arc<Mutex<dyn io::Write + Send + Sync>>);
+pub struct WriteHandle {
+    writer: Arc<Mutex<dyn io::Write + Send + Sync>>,
+    /// The buffer written to, if this handle was created with [`WriteHandle::new_string_writer`]
+    string_buffer: Option<Arc<Mutex<Vec<u8>>>>,
+}
 
 impl WriteHandle {
     pub fn new(writer: impl io::Write + Send + Sync + 'static) -> Self {
-        Self(Arc::new(Mutex::new(writer)))
+        Self {
+            writer: Arc::new(Mutex::new(writer)),
+            string_buffer: None,
+        }
+    }
+
+    /// Create a handle that writes into an in-memory buffer, readable with
+    /// [`WriteHandle::string_contents`]
+    pub fn new_string_writer() -> Self {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        Self {
+            writer: Arc::new(Mutex::new(SharedBuffer(buffer.clone()))),
+            string_buffer: Some(buffer),
+        }
     }
+
     /// Get the inner Arc<Mutex> directly to avoid nested locks
     pub fn inner(&self) -> std::sync::Arc<Mutex<dyn io::Write + Send + Sync>> {
-        self.0.clone()
+        self.writer.clone()
+    }
+
+    /// Get everything written so far, if this is a string writer
+    pub fn string_contents(&self) -> Option<String> {
+        self.string_buffer.as_ref().map(|buffer| {
+            String::from_utf8_lossy(
+                &buffer
+                    .lock()
+                    .expect("mutex poisoned while reading string writer"),
+            )
+            .into_owned()
+        })
     }
 }
 
 impl IHandle for WriteHandle {}
+
+/// An `io::Write` appending to a buffer shared with the [`WriteHandle`] that owns it
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .expect("mutex poisoned while writing to string writer")
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
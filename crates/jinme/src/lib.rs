@@ -15,6 +15,7 @@ pub mod prism;
 pub mod read2;
 pub mod set;
 pub mod symbol;
+pub mod tagged_literal;
 pub mod value;
 pub mod var;
 pub mod vector;
@@ -27,6 +28,7 @@ pub mod dependency {
     pub use opentelemetry;
     pub use opentelemetry_otlp;
     pub use opentelemetry_sdk;
+    pub use regex;
     pub use tokio;
     pub use tracing;
     pub use tracing_opentelemetry;
@@ -1,3 +1,29 @@
 pub mod lens;
 pub mod prism2;
 pub mod prism3;
+
+pub trait View<T> {
+    fn view(&self) -> T;
+}
+
+pub trait Preview<T> {
+    fn preview(&self) -> Option<T>;
+}
+
+impl View<i64> for crate::value::Value {
+    fn view(&self) -> i64 {
+        self.view_integer()
+    }
+}
+
+impl Preview<i64> for crate::value::Value {
+    fn preview(&self) -> Option<i64> {
+        self.preview_integer()
+    }
+}
+
+impl Preview<f64> for crate::value::Value {
+    fn preview(&self) -> Option<f64> {
+        crate::value::optics::preview_float(self).map(|f| f.into())
+    }
+}
@@ -1,4 +1,7 @@
-pub use crate::core::{self, ResolveError, apply, eval, resolve_or_panic, try_resolve};
+pub use crate::core::{
+    self, EvalError, ResolveError, apply, eval, macroexpand, macroexpand_1, resolve_or_panic,
+    try_apply, try_eval, try_macroexpand, try_macroexpand_1, try_resolve,
+};
 pub use crate::environment::{self, Environment, PtrEnvironment};
 pub use crate::eval_context::{self, EvalContext};
 pub use crate::float::{self, Float};
@@ -6,7 +9,9 @@ pub use crate::function::{
     self, Function, FunctionArity, FunctionBuilder, IFunction, PtrDynIFunction, PtrFunction,
     build_function, build_function_ptr, build_function_value, build_function_value_ptr, closure_fn,
 };
-pub use crate::handle::{self, BufReadHandle, Handle, IHandle, WriteHandle};
+pub use crate::handle::{
+    self, BufReadHandle, Handle, IHandle, RegexHandle, RegexMatch, RegexMatcherHandle, WriteHandle,
+};
 pub use crate::keyword::{self, Keyword, KeywordQualified, KeywordUnqualified};
 pub use crate::list::{self, List};
 pub use crate::map::{self, Map};
@@ -16,9 +21,10 @@ pub use crate::namespace::{
 };
 pub use crate::optics;
 pub use crate::prism::{self, Prism, PrismNil};
-pub use crate::read2::{self, read};
+pub use crate::read2::{self, ReadError, read};
 pub use crate::set::{self, Set};
 pub use crate::symbol::{self, Symbol, SymbolQualified, SymbolUnqualified};
+pub use crate::tagged_literal::{self, TaggedLiteral};
 pub use crate::value::{self, PtrValue, Value};
 pub use crate::var::{self, PtrVar, Var};
 pub use crate::vector::{self, Vector};
@@ -1,13 +1,19 @@
-use crate::prelude::*;
-use ::std::sync::Arc;
+use crate::{prelude::*, value::CHAR_NAMES};
+use ::std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt, io,
+    sync::Arc,
+};
 use nom::{
     IResult,
     branch::alt,
-    bytes::complete::tag,
+    bytes::complete::{tag, take_till},
     character::complete::{char, none_of, one_of},
     combinator::{cut, map, opt, recognize, value},
+    error::ErrorKind,
     multi::{many0, many1, separated_list0},
-    sequence::{delimited, preceded, separated_pair, tuple},
+    sequence::{delimited, pair, preceded, tuple},
 };
 
 /// Represents a parsing anomaly with a category and message.
@@ -44,10 +50,6 @@ impl AnomalyMap {
         ]))
     }
 
-    fn new_empty() -> Self {
-        Self(Map::new_empty())
-    }
-
     pub fn set_category(&mut self, category: KeywordUnqualified) -> &mut Self {
         self.0.insert(
             Value::keyword_qualified_ptr("jinme.anomalies", "category"),
@@ -104,13 +106,184 @@ impl AnomalyMap {
     }
 }
 
+/// The message, preceded by where it happened and followed by what was expected there and the
+/// excerpt of the input, when the anomaly has them:
+///
+/// ```text
+/// forms.clj:1:3: unmatched delimiter ], expected )
+/// (a]
+///   ^
+/// ```
+impl fmt::Display for AnomalyMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let get_string = |name: &str| {
+            self.0
+                .get(&Value::keyword_unqualified_ptr(name))
+                .and_then(|value| value.preview_string())
+        };
+        if let Some(position) = SourcePosition::from_meta(&self.0) {
+            write!(f, "{position}: ")?;
+        }
+        match self
+            .0
+            .get(&Value::keyword_qualified_ptr("jinme.anomalies", "message"))
+            .and_then(|message| message.preview_string())
+        {
+            Some(message) => write!(f, "{message}")?,
+            None => write!(f, "{}", self.0)?,
+        }
+        if let Some(expected) = get_string("expected") {
+            write!(f, ", expected {expected}")?;
+        }
+        if let Some(excerpt) = get_string("excerpt") {
+            write!(f, "\n{excerpt}")?;
+        }
+        Ok(())
+    }
+}
+
 impl From<Map> for AnomalyMap {
     fn from(map: Map) -> Self {
         Self(map)
     }
 }
 
-pub fn read(env: PtrEnvironment, input: &str) -> Result<(&str, Option<PtrValue>), AnomalyMap> {
+/// Where a form was read from, as recorded in its metadata by [`Reader`].
+///
+/// Lines and columns are 1-based, columns count characters, and the end is exclusive, so
+/// `hello` read at the start of the input spans column 1 to end column 6. The metadata keys
+/// are `:line`, `:column`, `:end-line`, `:end-column` and, when the reader knows which file it
+/// is reading, `:file`.
+///
+/// # Example
+///
+/// ```
+/// # use jinme::prelude::*;
+/// # use jinme::read2::SourcePosition;
+/// # use std::sync::Arc;
+/// # let mut builder = Environment::builder();
+/// # builder.insert_namespace(Arc::new(Namespace::new_empty("user")));
+/// # builder.set_current_namespace_var("user", "*ns*");
+/// # let env = builder.build_ptr();
+/// let (_, form) = read(env, "\n  (inc 1)").unwrap();
+/// let position = SourcePosition::from_value(&form.unwrap()).unwrap();
+/// assert_eq!((position.line, position.column), (2, 3));
+/// assert_eq!((position.end_line, position.end_column), (2, 10));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub file: Option<String>,
+}
+
+impl SourcePosition {
+    /// Reads a position back out of metadata, if it has at least `:line` and `:column`.
+    pub fn from_meta(meta: &Map) -> Option<Self> {
+        let get_usize = |name: &str| {
+            meta.get(&Value::keyword_unqualified_ptr(name))
+                .and_then(|value| value.preview_integer())
+                .and_then(|integer| usize::try_from(integer).ok())
+        };
+        let line = get_usize("line")?;
+        let column = get_usize("column")?;
+        Some(Self {
+            line,
+            column,
+            end_line: get_usize("end-line").unwrap_or(line),
+            end_column: get_usize("end-column").unwrap_or(column),
+            file: meta
+                .get(&Value::keyword_unqualified_ptr("file"))
+                .and_then(|file| file.preview_string()),
+        })
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        value::optics::preview_meta_ref(value).and_then(|meta| Self::from_meta(meta))
+    }
+
+    /// Returns `meta` with this position's keys added, replacing any position already there.
+    pub fn assoc_to_meta(&self, meta: Option<Arc<Map>>) -> Option<Arc<Map>> {
+        let integer = |n: usize| Value::integer_ptr(n as i64);
+        let meta = meta
+            .assoc(Value::keyword_unqualified_ptr("line"), integer(self.line))
+            .assoc(
+                Value::keyword_unqualified_ptr("column"),
+                integer(self.column),
+            )
+            .assoc(
+                Value::keyword_unqualified_ptr("end-line"),
+                integer(self.end_line),
+            )
+            .assoc(
+                Value::keyword_unqualified_ptr("end-column"),
+                integer(self.end_column),
+            );
+        match &self.file {
+            Some(file) => meta.assoc(
+                Value::keyword_unqualified_ptr("file"),
+                Value::string_ptr(file.to_owned()),
+            ),
+            None => meta,
+        }
+    }
+}
+
+/// `file:line:column`, or `line:column` without a file
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Why a form couldn't be read.
+///
+/// Either way the [`AnomalyMap`] says what went wrong and where: `:line` and `:column`,
+/// `:file` if the reader was given one, `:expected` for the token that would have made sense
+/// there and an `:excerpt` of the input line with the trouble underlined.
+#[derive(Debug, Clone)]
+pub enum ReadError {
+    /// The input ends partway through a form, e.g. `(+ 1` or `"abc`. Reading it again with
+    /// more input appended may succeed, which is how the REPL reads forms spanning lines.
+    Incomplete(AnomalyMap),
+    /// The input can't be read, whatever follows it
+    Anomaly(AnomalyMap),
+}
+
+impl ReadError {
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, Self::Incomplete(_))
+    }
+
+    /// Returns the anomaly, with category `:incomplete-input` for [`ReadError::Incomplete`] and
+    /// `:erroneous-input` otherwise.
+    pub fn to_anomaly_map(&self) -> AnomalyMap {
+        match self {
+            Self::Incomplete(anomaly) | Self::Anomaly(anomaly) => anomaly.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Incomplete(anomaly) | Self::Anomaly(anomaly) => write!(f, "{anomaly}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Reads the first form in `input`, returning the input remaining after it.
+///
+/// Returns `None` for the form if `input` holds only whitespace, comments and discarded forms,
+/// and [`ReadError::Incomplete`] if it ends partway through a form.
+pub fn read(env: PtrEnvironment, input: &str) -> Result<(&str, Option<PtrValue>), ReadError> {
     // let resolve_fn = |_: &_, env: _, symbol: &_| try_resolve(env, symbol).ok();
     let reader = Reader::new(
         // resolve_fn,
@@ -118,13 +291,128 @@ pub fn read(env: PtrEnvironment, input: &str) -> Result<(&str, Option<PtrValue>)
     reader.try_read(env, input)
 }
 
+/// Reads forms one at a time from `input`, pulling lines from it only as a form needs them.
+///
+/// Text pulled from `input` but not yet read stays buffered, and is what reading this as an
+/// [`io::BufRead`] gives first, so forms and lines can be read from the same input in turn.
+///
+/// # Example
+///
+/// ```
+/// # use jinme::prelude::*;
+/// # use jinme::read2::{Reader, StreamReader};
+/// use std::io::BufRead as _;
+/// # use std::sync::Arc;
+/// # let mut builder = Environment::builder();
+/// # builder.insert_namespace(Arc::new(Namespace::new_empty("user")));
+/// # builder.set_current_namespace_var("user", "*ns*");
+/// # let env = builder.build_ptr();
+/// let mut stream = StreamReader::new(std::io::Cursor::new("(a\n b) c rest of line\n"));
+/// let reader = Reader::new();
+/// assert_eq!(stream.read_form(&reader, env.clone()).unwrap().unwrap().to_string(), "(a b)");
+/// assert_eq!(stream.read_form(&reader, env.clone()).unwrap().unwrap().to_string(), "c");
+/// let mut line = String::new();
+/// stream.read_line(&mut line).unwrap();
+/// assert_eq!(line, " rest of line\n");
+/// ```
+pub struct StreamReader<R> {
+    input: R,
+    /// Text pulled from `input` that hasn't been read yet
+    pending: String,
+}
+
+impl<R: io::BufRead> StreamReader<R> {
+    pub fn new(input: R) -> Self {
+        Self {
+            input,
+            pending: String::new(),
+        }
+    }
+
+    /// Reads the next form with `reader`, or `None` if the input ends before one starts.
+    ///
+    /// Returns [`ReadError::Incomplete`] if the input ends partway through a form. After any
+    /// other error, the text buffered when it happened is dropped.
+    pub fn read_form(
+        &mut self,
+        reader: &Reader,
+        env: PtrEnvironment,
+    ) -> Result<Option<PtrValue>, ReadError> {
+        loop {
+            let result = reader
+                .try_read(env.clone(), &self.pending)
+                .map(|(remaining, form)| (self.pending.len() - remaining.len(), form));
+            match result {
+                Ok((read_len, Some(form))) => {
+                    self.pending.drain(..read_len);
+                    return Ok(Some(form));
+                }
+                // only whitespace, comments and discarded forms so far
+                Ok((_, None)) => {
+                    self.pending.clear();
+                    if self.pull_line()? == 0 {
+                        return Ok(None);
+                    }
+                }
+                Err(err @ ReadError::Incomplete(_)) => {
+                    if self.pull_line()? == 0 {
+                        return Err(err);
+                    }
+                }
+                Err(err) => {
+                    self.pending.clear();
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    /// Appends the next line of the input to the pending text, returning its length in bytes
+    fn pull_line(&mut self) -> Result<usize, ReadError> {
+        self.input.read_line(&mut self.pending).map_err(|err| {
+            ReadError::Anomaly(AnomalyMap::new(
+                KeywordUnqualified::new("fault"),
+                format!("failed to read input: {err}"),
+            ))
+        })
+    }
+}
+
+impl<R: io::BufRead> io::Read for StreamReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = io::BufRead::fill_buf(self)?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        io::BufRead::consume(self, len);
+        Ok(len)
+    }
+}
+
+impl<R: io::BufRead> io::BufRead for StreamReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pending.is_empty() {
+            self.input.fill_buf()
+        } else {
+            Ok(self.pending.as_bytes())
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.pending.is_empty() {
+            self.input.consume(amt)
+        } else {
+            self.pending.drain(..amt);
+        }
+    }
+}
+
 pub trait TryRead {
     // type Error;
     fn try_read<'r, 'o, 'i: 'o>(
         &'r self,
         env: PtrEnvironment,
         input: &'i str,
-    ) -> Result<(&'o str, Option<PtrValue>), AnomalyMap>;
+    ) -> Result<(&'o str, Option<PtrValue>), ReadError>;
     // fn try_read_nil     <'r, 'o, 'i: 'o>(&'r self, env: PtrEnvironment, input: &'i str) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>>;
     // fn try_read_boolean <'r, 'o, 'i: 'o>(&'r self, env: PtrEnvironment, input: &'i str) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>>;
     // fn try_read_number  <'r, 'o, 'i: 'o>(&'r self, env: PtrEnvironment, input: &'i str) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>>;
@@ -139,13 +427,342 @@ pub trait TryRead {
 
 pub struct Reader {
     // resolve_fn: Box<dyn for<'this, 'sym> Fn(&'this Self, PtrEnvironment, &'sym Symbol) -> Option<PtrVar>>,
+    /// The whole text being read, when inputs are successive remainders of it
+    source: Option<Arc<SourceText>>,
+    /// Recorded as `:file` in the metadata of every form read
+    file: Option<String>,
+    /// The text positions are counted from during the current [`TryRead::try_read`]
+    origin: RefCell<Option<Arc<SourceText>>>,
+    /// Why the current [`TryRead::try_read`] failed, if a form could tell
+    failure: RefCell<Option<ReadFailure>>,
+    /// The `%` args used so far in the `#(...)` being read, if any
+    fn_literal: RefCell<Option<FnLiteralArgs>>,
+    /// The readers for `#tag form`, by tag, consulted after `clojure.core/*data-readers*`
+    data_readers: HashMap<Symbol, DataReaderFn>,
+    unknown_tags: UnknownTags,
+    reader_conditionals: ReaderConditionals,
+    /// The features `#?(feature form ...)` picks forms for, besides `:default`
+    features: HashSet<Keyword>,
+    /// Whether the form being read is in a branch of a reader conditional that wasn't picked,
+    /// so is only read to be skipped
+    suppress_read: Cell<bool>,
+}
+
+/// Reads the value of `#tag form` from `form`, or says why it can't.
+pub type DataReaderFn = Arc<dyn Fn(PtrValue) -> Result<PtrValue, String>>;
+
+/// What a [`Reader`] does with `#tag form` when it has no reader for `tag`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownTags {
+    /// Fail to read
+    #[default]
+    Error,
+    /// Read a [`TaggedLiteral`] of the tag and form
+    TaggedLiteral,
+}
+
+/// Whether a [`Reader`] reads reader conditionals, `#?(feature form ...)` and
+/// `#?@(feature [forms] ...)`. As in Clojure, they are only read from `.cljc` files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReaderConditionals {
+    /// Fail to read them
+    #[default]
+    Disallow,
+    /// Read the form of the first branch for one of the reader's features, or `:default`
+    Allow,
+}
+
+/// The feature every [`Reader`] starts with, jinme's platform feature
+pub const PLATFORM_FEATURE: &str = "jinme";
+
+/// The readers every [`Reader`] starts with: `#inst` and `#uuid`
+fn default_data_readers() -> HashMap<Symbol, DataReaderFn> {
+    let inst: DataReaderFn =
+        Arc::new(|form| tagged_literal::read_inst(&form).map(Value::tagged_ptr));
+    let uuid: DataReaderFn =
+        Arc::new(|form| tagged_literal::read_uuid(&form).map(Value::tagged_ptr));
+    HashMap::from([
+        (Symbol::new_unqualified("inst"), inst),
+        (Symbol::new_unqualified("uuid"), uuid),
+    ])
+}
+
+/// The parameters of a `#(...)` function literal, named as its `%` args are read.
+#[derive(Default)]
+struct FnLiteralArgs {
+    /// `%n` for each n used, with `%` being `%1`
+    positional: BTreeMap<usize, Symbol>,
+    /// `%&`, if used
+    rest: Option<Symbol>,
+}
+
+/// Where and why reading failed, recorded by the innermost form that could tell.
+struct ReadFailure {
+    /// Length of the input remaining from the failure on, which locates it in the origin
+    rest_len: usize,
+    /// Length in bytes of the text to underline
+    span: usize,
+    message: String,
+    expected: Option<String>,
+}
+
+impl ReadFailure {
+    fn new(at: &str, span: usize, message: String, expected: Option<String>) -> Self {
+        Self {
+            rest_len: at.len(),
+            span,
+            message,
+            expected,
+        }
+    }
+
+    /// A failure at input the parsers gave up on without saying why
+    fn unexpected(at: &str) -> Self {
+        match at.chars().next() {
+            None => Self::new(at, 0, "unexpected end of input".to_owned(), None),
+            Some(c @ (')' | ']' | '}')) => {
+                Self::new(at, c.len_utf8(), format!("unmatched delimiter {c}"), None)
+            }
+            Some(c) => Self::new(at, c.len_utf8(), format!("unexpected {c}"), None),
+        }
+    }
+}
+
+/// Text being read, with the byte offsets its lines start at, for turning offsets into lines
+/// and columns.
+struct SourceText {
+    text: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceText {
+    fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self {
+            text: text.to_owned(),
+            line_starts,
+        }
+    }
+
+    /// The 1-based line and column that `rest`, a suffix of this text, starts at
+    fn line_column(&self, rest: &str) -> (usize, usize) {
+        self.line_column_at(self.text.len() - rest.len())
+    }
+
+    /// The 1-based line and column of the byte `offset`
+    fn line_column_at(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self.text[line_start..offset].chars().count() + 1;
+        (line, column)
+    }
+
+    /// The line holding the byte `offset`, followed by a line underlining `span` bytes from
+    /// `offset`, or at least one character
+    fn excerpt(&self, offset: usize, span: usize) -> String {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let line_end = self
+            .line_starts
+            .get(line)
+            .map_or(self.text.len(), |next_start| next_start - 1);
+        let indent = self.text[line_start..offset]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let underlined = self.text[offset..(offset + span).min(line_end)]
+            .chars()
+            .count()
+            .max(1);
+        format!(
+            "{}\n{indent}{}",
+            self.text[line_start..line_end].trim_end_matches('\r'),
+            "^".repeat(underlined)
+        )
+    }
 }
 
 impl Reader {
+    /// A reader that counts positions from the start of each input it reads.
     pub fn new() -> Self {
         Self {
             // resolve_fn: Box::new(resolve_fn),
+            source: None,
+            file: None,
+            origin: RefCell::new(None),
+            failure: RefCell::new(None),
+            fn_literal: RefCell::new(None),
+            data_readers: default_data_readers(),
+            unknown_tags: UnknownTags::default(),
+            reader_conditionals: ReaderConditionals::default(),
+            features: HashSet::from([Keyword::new_unqualified(PLATFORM_FEATURE)]),
+            suppress_read: Cell::new(false),
+        }
+    }
+
+    /// A reader for the forms of `source`, read one at a time by passing what remains of it to
+    /// [`TryRead::try_read`]. Positions are counted from the start of `source` and carry `file`.
+    /// Reader conditionals are allowed if `file` is a `.cljc` file.
+    pub fn with_source(source: &str, file: Option<String>) -> Self {
+        let reader_conditionals = match &file {
+            Some(file) if file.ends_with(".cljc") => ReaderConditionals::Allow,
+            _ => ReaderConditionals::Disallow,
+        };
+        Self {
+            source: Some(Arc::new(SourceText::new(source))),
+            file,
+            origin: RefCell::new(None),
+            failure: RefCell::new(None),
+            fn_literal: RefCell::new(None),
+            data_readers: default_data_readers(),
+            unknown_tags: UnknownTags::default(),
+            reader_conditionals,
+            features: HashSet::from([Keyword::new_unqualified(PLATFORM_FEATURE)]),
+            suppress_read: Cell::new(false),
+        }
+    }
+
+    /// This reader, reading `#tag form` with `reader` in place of any reader it had for `tag`,
+    /// including the built-in `#inst` and `#uuid`.
+    pub fn with_data_reader(
+        mut self,
+        tag: Symbol,
+        reader: impl Fn(PtrValue) -> Result<PtrValue, String> + 'static,
+    ) -> Self {
+        self.data_readers.insert(tag, Arc::new(reader));
+        self
+    }
+
+    /// This reader, doing as `unknown_tags` says with tags it has no reader for.
+    pub fn with_unknown_tags(mut self, unknown_tags: UnknownTags) -> Self {
+        self.unknown_tags = unknown_tags;
+        self
+    }
+
+    /// This reader, reading reader conditionals or not as `reader_conditionals` says.
+    pub fn with_reader_conditionals(mut self, reader_conditionals: ReaderConditionals) -> Self {
+        self.reader_conditionals = reader_conditionals;
+        self
+    }
+
+    /// This reader, with `features` in place of its features, which are `:jinme` to start with.
+    /// `:default` is always a feature.
+    pub fn with_features(mut self, features: impl IntoIterator<Item = Keyword>) -> Self {
+        self.features = features.into_iter().collect();
+        self
+    }
+
+    /// Records why reading failed, unless a form nested deeper already has.
+    fn fail(&self, failure: ReadFailure) {
+        self.failure.borrow_mut().get_or_insert(failure);
+    }
+
+    /// Records that the form opened by `open` at the start of `input` is never closed if reading
+    /// it ran out of input.
+    fn note_unclosed<'i, T>(
+        &self,
+        result: IResult<&'i str, T>,
+        input: &'i str,
+        open: &str,
+        close: char,
+    ) -> IResult<&'i str, T> {
+        if let Err(nom::Err::Incomplete(_)) = &result {
+            self.fail(ReadFailure::new(
+                input,
+                open.len(),
+                format!("unclosed {open}"),
+                Some(close.to_string()),
+            ));
+        }
+        result
+    }
+
+    /// The delimiter `close` ending a collection or string. Running out of input means the
+    /// form is incomplete, and anything else can't be read.
+    fn try_read_closing<'i>(&self, input: &'i str, close: char) -> IResult<&'i str, char> {
+        match input.chars().next() {
+            None => Err(nom::Err::Incomplete(nom::Needed::new(close.len_utf8()))),
+            Some(c) if c == close => Ok((&input[c.len_utf8()..], c)),
+            Some(_) => {
+                let mut failure = ReadFailure::unexpected(input);
+                failure.expected = Some(close.to_string());
+                self.fail(failure);
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Char,
+                )))
+            }
+        }
+    }
+
+    /// The anomaly for `failure`, with where it happened and an excerpt of the input there.
+    fn failure_anomaly(&self, category: &str, failure: ReadFailure) -> AnomalyMap {
+        let mut anomaly = AnomalyMap::new(KeywordUnqualified::new(category), failure.message);
+        if let Some(origin) = self.origin.borrow().as_ref() {
+            let offset = origin.text.len() - failure.rest_len;
+            let (line, column) = origin.line_column_at(offset);
+            anomaly
+                .insert(
+                    Value::keyword_unqualified_ptr("line"),
+                    Value::integer_ptr(line as i64),
+                )
+                .insert(
+                    Value::keyword_unqualified_ptr("column"),
+                    Value::integer_ptr(column as i64),
+                )
+                .insert(
+                    Value::keyword_unqualified_ptr("excerpt"),
+                    Value::string_ptr(origin.excerpt(offset, failure.span)),
+                );
+        }
+        if let Some(file) = &self.file {
+            anomaly.insert(
+                Value::keyword_unqualified_ptr("file"),
+                Value::string_ptr(file.to_owned()),
+            );
+        }
+        if let Some(expected) = failure.expected {
+            anomaly.insert(
+                Value::keyword_unqualified_ptr("expected"),
+                Value::string_ptr(expected),
+            );
+        }
+        anomaly
+    }
+
+    /// Adds the position of `form`, read from `start` up to `end`, to its metadata. As in
+    /// Clojure, only symbols and collections get one.
+    fn with_position(&self, form: PtrValue, start: &str, end: &str) -> PtrValue {
+        if !matches!(
+            form.as_ref(),
+            Value::Symbol(..)
+                | Value::List(..)
+                | Value::Vector(..)
+                | Value::Set(..)
+                | Value::Map(..)
+        ) {
+            return form;
+        }
+        // a position given as `^{:line n :column n}` metadata, or the target's after `^`, stays
+        if SourcePosition::from_value(&form).is_some() {
+            return form;
         }
+        let origin = self.origin.borrow();
+        let Some(origin) = origin.as_ref() else {
+            return form;
+        };
+        let (line, column) = origin.line_column(start);
+        let (end_line, end_column) = origin.line_column(end);
+        let position = SourcePosition {
+            line,
+            column,
+            end_line,
+            end_column,
+            file: self.file.clone(),
+        };
+        value::optics::modify_meta(form, |meta| position.assoc_to_meta(meta))
     }
 
     // fn resolve(
@@ -161,21 +778,449 @@ impl Reader {
         env: PtrEnvironment,
         input: &'i str,
     ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
-        let parser = alt((
-            // TODO: https://clojure.org/guides/reader_conditionals
+        let mut parser = alt((
+            |i| self.try_read_discard(env.clone(), i),
+            |i| self.try_read_conditional(env.clone(), i),
+            |i| self.try_read_quote(env.clone(), i),
+            |i| self.try_read_syntax_quote(env.clone(), i),
+            |i| self.try_read_unquote(env.clone(), i),
+            |i| self.try_read_meta(env.clone(), i),
+            |i| self.try_read_var_quote(env.clone(), i),
+            |i| self.try_read_fn_literal(env.clone(), i),
+            |i| self.try_read_regex(env.clone(), i),
             |i| self.try_read_nil(env.clone(), i),
             |i| self.try_read_boolean(env.clone(), i),
             |i| self.try_read_number(env.clone(), i),
             |i| self.try_read_string(env.clone(), i),
+            |i| self.try_read_char(env.clone(), i),
             |i| self.try_read_list(env.clone(), i),
             |i| self.try_read_vector(env.clone(), i),
             |i| self.try_read_set(env.clone(), i),
             |i| self.try_read_map(env.clone(), i),
+            |i| self.try_read_tagged(env.clone(), i),
             |i| self.try_read_keyword(env.clone(), i),
             |i| self.try_read_symbol(env.clone(), i),
         ));
-        let mut parser = preceded(ws0, parser);
-        parser(input)
+        let (input, _) = ws0(input)?;
+        // a form was expected but the input ran out, e.g. after `(` or `'`
+        if input.is_empty() {
+            return Err(nom::Err::Incomplete(nom::Needed::Unknown));
+        }
+        let (remaining, form) = parser(input)?;
+        Ok((
+            remaining,
+            form.map(|form| self.with_position(form, input, remaining)),
+        ))
+    }
+
+    /// `#_form` -> nothing, discarding the next form. Stacked discards `#_ #_ a b` discard
+    /// one form each.
+    fn try_read_discard<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let (mut remaining, _) = tag("#_")(input)?;
+        // a nested discard reads as nothing, so keep reading until a form is discarded
+        loop {
+            let (next_remaining, discarded) =
+                cut(|i| self.try_read_any(env.clone(), i))(remaining)?;
+            remaining = next_remaining;
+            if discarded.is_some() {
+                return Ok((remaining, None));
+            }
+        }
+    }
+
+    /// `#?(feature form ...)` -> the form of the first branch for one of this reader's
+    /// features, or `:default`, or nothing if there is no such branch. The other forms are
+    /// read, but only to be skipped.
+    fn try_read_conditional<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let (remaining, (splicing, form)) = self.try_read_reader_conditional(env, input)?;
+        if splicing {
+            self.fail(ReadFailure::new(
+                input,
+                input.len() - remaining.len(),
+                "reader conditional splicing is not allowed at the top level".to_owned(),
+                None,
+            ));
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+        Ok((remaining, form))
+    }
+
+    /// An element of a collection: the elements of the picked form of `#?@(feature [forms] ...)`
+    /// spliced in, or whatever else is there, which may be nothing.
+    fn try_read_element<'i>(
+        &self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> IResult<&'i str, Vec<PtrValue>> {
+        let (input, _) = ws0(input)?;
+        if !input.starts_with("#?@") {
+            let (remaining, form) = self.try_read_any(env, input)?;
+            return Ok((remaining, form.into_iter().collect()));
+        }
+        let (remaining, (_, form)) = self.try_read_reader_conditional(env, input)?;
+        match form.as_deref() {
+            None => Ok((remaining, vec![])),
+            Some(Value::List(list, _)) => Ok((remaining, list.iter().cloned().collect())),
+            Some(Value::Vector(vector, _)) => Ok((remaining, vector.iter().cloned().collect())),
+            Some(form) => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - remaining.len(),
+                    format!("spliced form in reader conditional must be a list or vector, but got: {form}"),
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )))
+            }
+        }
+    }
+
+    /// `#?(...)` or `#?@(...)` -> whether it splices, and the form it picks, if any
+    fn try_read_reader_conditional<'i>(
+        &self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> IResult<&'i str, (bool, Option<PtrValue>)> {
+        let (rest, _) = tag("#?")(input)?;
+        let (rest, splicing) = map(opt(char('@')), |at| at.is_some())(rest)?;
+        let open = if splicing { "#?@(" } else { "#?(" };
+        let failure = |message: &str| {
+            self.fail(ReadFailure::new(
+                input,
+                open.len(),
+                message.to_owned(),
+                None,
+            ));
+            Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )))
+        };
+        if self.reader_conditionals == ReaderConditionals::Disallow {
+            return failure("reader conditionals are only allowed when reading .cljc files");
+        }
+        let (rest, _) = ws0(rest)?;
+        match rest.chars().next() {
+            None => return Err(nom::Err::Incomplete(nom::Needed::new(1))),
+            Some('(') => {}
+            Some(_) => return failure("reader conditional body must be a list"),
+        }
+        let result = self.try_read_conditional_branches(env, &rest[1..]);
+        let (remaining, form) = self.note_unclosed(result, input, open, ')')?;
+        Ok((remaining, (splicing, form)))
+    }
+
+    /// The `feature form` pairs of a reader conditional up to its closing `)` -> the form of
+    /// the first feature this reader has
+    fn try_read_conditional_branches<'i>(
+        &self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> IResult<&'i str, Option<PtrValue>> {
+        let default = Keyword::new_unqualified("default");
+        let mut picked = None;
+        let mut rest = input;
+        loop {
+            let (feature_input, _) = ws0(rest)?;
+            if let Some(remaining) = feature_input.strip_prefix(')') {
+                return Ok((remaining, picked));
+            }
+            let (form_input, feature) = self.try_read_present(env.clone(), feature_input)?;
+            let Value::Keyword(feature, _) = feature.as_ref() else {
+                self.fail(ReadFailure::new(
+                    feature_input,
+                    feature_input.len() - form_input.len(),
+                    format!("reader conditional feature must be a keyword, but got: {feature}"),
+                    None,
+                ));
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    feature_input,
+                    ErrorKind::Verify,
+                )));
+            };
+            let (form_input, _) = ws0(form_input)?;
+            if form_input.starts_with(')') {
+                self.fail(ReadFailure::new(
+                    form_input,
+                    1,
+                    "reader conditional requires an even number of forms".to_owned(),
+                    None,
+                ));
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    form_input,
+                    ErrorKind::Verify,
+                )));
+            }
+            let picks =
+                picked.is_none() && (*feature == default || self.features.contains(feature));
+            let (remaining, form) = if picks {
+                self.try_read_present(env.clone(), form_input)?
+            } else {
+                let suppressed = self.suppress_read.replace(true);
+                let result = self.try_read_present(env.clone(), form_input);
+                self.suppress_read.set(suppressed);
+                result?
+            };
+            if picks {
+                picked = Some(form);
+            }
+            rest = remaining;
+        }
+    }
+
+    /// `'form` -> `(quote form)`
+    fn try_read_quote<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let mut parser = preceded(char('\''), cut(|i| self.try_read_any(env.clone(), i)));
+        let (remaining, quoted) = parser(input)?;
+        Ok((
+            remaining,
+            quoted.map(|form| {
+                Value::new_list_ptr(vec![Value::symbol_unqualified_ptr("quote"), form])
+            }),
+        ))
+    }
+
+    /// `` `form `` -> `form` as code that builds it, with symbols namespace-qualified, `~x`
+    /// replaced by `x` and `~@xs` spliced in. See [`syntax_quote`].
+    fn try_read_syntax_quote<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let mut parser = preceded(char('`'), cut(|i| self.try_read_any(env.clone(), i)));
+        let (remaining, quoted) = parser(input)?;
+        let Some(form) = quoted else {
+            return Ok((remaining, None));
+        };
+        let form = syntax_quote(&env, &form, &mut HashMap::new()).ok_or_else(|| {
+            self.fail(ReadFailure::new(
+                input,
+                input.len() - remaining.len(),
+                "unquote-splicing used outside of a list, vector or set".to_owned(),
+                None,
+            ));
+            nom::Err::Failure(nom::error::Error::new(input, ErrorKind::Verify))
+        })?;
+        Ok((remaining, Some(form)))
+    }
+
+    /// `~form` -> `(clojure.core/unquote form)`
+    /// `~@form` -> `(clojure.core/unquote-splicing form)`
+    fn try_read_unquote<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let mut parser = preceded(
+            char('~'),
+            pair(opt(char('@')), cut(|i| self.try_read_any(env.clone(), i))),
+        );
+        let (remaining, (splicing, unquoted)) = parser(input)?;
+        let name = if splicing.is_some() {
+            "unquote-splicing"
+        } else {
+            "unquote"
+        };
+        Ok((
+            remaining,
+            unquoted.map(|form| {
+                Value::new_list_ptr(vec![
+                    Value::symbol_qualified_ptr("clojure.core", name),
+                    form,
+                ])
+            }),
+        ))
+    }
+
+    /// `^{:k v} form` -> `form` with `:k v` added to its metadata. `^:k form` is short for
+    /// `^{:k true} form`, and `^Tag form` and `^"Tag" form` for `^{:tag Tag} form`. Stacked
+    /// metadata `^:a ^:b form` is merged, with the leftmost winning.
+    fn try_read_meta<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let (rest, _) = char('^')(input)?;
+        let (rest, meta) = self.try_read_present(env.clone(), rest)?;
+        let entries = match meta.as_ref() {
+            Value::Map(map, _) => map
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<Vec<_>>(),
+            Value::Keyword(..) => vec![(meta.clone(), Value::boolean_ptr(true))],
+            Value::Symbol(..) | Value::String(..) => {
+                vec![(Value::keyword_unqualified_ptr("tag"), meta.clone())]
+            }
+            _ => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - rest.len(),
+                    format!("metadata must be a symbol, keyword, string or map, but got: {meta}"),
+                    None,
+                ));
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )));
+            }
+        };
+        let (remaining, form) = self.try_read_present(env, rest)?;
+        if !matches!(
+            form.as_ref(),
+            Value::Symbol(..)
+                | Value::List(..)
+                | Value::Vector(..)
+                | Value::Set(..)
+                | Value::Map(..)
+        ) {
+            self.fail(ReadFailure::new(
+                input,
+                input.len() - remaining.len(),
+                format!("metadata can only be applied to symbols and collections, but got: {form}"),
+                None,
+            ));
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+        let form = value::optics::modify_meta(form, |meta| {
+            entries
+                .iter()
+                .fold(meta, |meta, (k, v)| meta.assoc(k.clone(), v.clone()))
+        });
+        Ok((remaining, Some(form)))
+    }
+
+    /// `#'sym` -> `(var sym)`
+    fn try_read_var_quote<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let mut parser = preceded(tag("#'"), cut(|i| self.try_read_any(env.clone(), i)));
+        let (remaining, quoted) = parser(input)?;
+        Ok((
+            remaining,
+            quoted
+                .map(|form| Value::new_list_ptr(vec![Value::symbol_unqualified_ptr("var"), form])),
+        ))
+    }
+
+    /// `#(f % %2 %&)` -> `(fn* [p1 p2 & rest] (f p1 p2 rest))`, with a parameter for every `%n`
+    /// up to the highest used, `%` being `%1`, and a rest parameter if `%&` is used. The
+    /// parameters are gensyms, so they can't capture anything from around the literal.
+    fn try_read_fn_literal<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let (rest, _) = tag("#(")(input)?;
+        if self.fn_literal.borrow().is_some() {
+            self.fail(ReadFailure::new(
+                input,
+                2,
+                "nested #()s are not allowed".to_owned(),
+                None,
+            ));
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+        *self.fn_literal.borrow_mut() = Some(FnLiteralArgs::default());
+        // the body is read as a list from the `(`, taking the args back out however it ends
+        let result = self.try_read_list(env, &input[input.len() - rest.len() - 1..]);
+        let args = self.fn_literal.take().unwrap_or_default();
+        let (remaining, body) = result?;
+
+        let arity = args.positional.keys().next_back().copied().unwrap_or(0);
+        let mut params = (1..=arity)
+            .map(|n| {
+                let param = args
+                    .positional
+                    .get(&n)
+                    .cloned()
+                    .unwrap_or_else(|| fn_literal_param(&format!("p{n}")));
+                Value::symbol_ptr(param)
+            })
+            .collect::<Vec<_>>();
+        if let Some(rest) = args.rest {
+            params.push(Value::symbol_unqualified_ptr("&"));
+            params.push(Value::symbol_ptr(rest));
+        }
+        Ok((
+            remaining,
+            body.map(|body| {
+                Value::new_list_ptr(vec![
+                    Value::symbol_unqualified_ptr("fn*"),
+                    Value::new_vector_ptr(params),
+                    body,
+                ])
+            }),
+        ))
+    }
+
+    /// The parameter `name` stands for inside the `#(...)` being read, if it is a `%` arg, or
+    /// why it can't be one.
+    fn fn_literal_arg(&self, name: &str) -> Option<Result<Symbol, String>> {
+        let arg = name.strip_prefix('%')?;
+        let mut fn_literal = self.fn_literal.borrow_mut();
+        let args = fn_literal.as_mut()?;
+        let param = match arg {
+            "&" => args
+                .rest
+                .get_or_insert_with(|| fn_literal_param("rest"))
+                .clone(),
+            _ => {
+                let n = match arg {
+                    "" => 1,
+                    _ => match arg.parse::<usize>() {
+                        Ok(n) if n >= 1 && !arg.starts_with(['+', '0']) => n,
+                        _ => {
+                            return Some(Err(format!(
+                                "arg literal must be %, %& or %n with n a positive integer, but got: {name}"
+                            )));
+                        }
+                    },
+                };
+                args.positional
+                    .entry(n)
+                    .or_insert_with(|| fn_literal_param(&format!("p{n}")))
+                    .clone()
+            }
+        };
+        Some(Ok(param))
+    }
+
+    /// The next form, skipping discarded ones, for syntax that must be followed by a form.
+    fn try_read_present<'i>(
+        &self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> IResult<&'i str, PtrValue> {
+        let (remaining, form) = cut(|i| self.try_read_any(env.clone(), i))(input)?;
+        match form {
+            Some(form) => Ok((remaining, form)),
+            None => self.try_read_present(env, remaining),
+        }
     }
 
     fn try_read_nil<'r, 'o, 'i: 'o>(
@@ -198,54 +1243,203 @@ impl Reader {
         ))(input)
     }
 
+    /// Numbers in Clojure's syntax: integers in decimal, hex (`0xFF`), octal (`017`) or any
+    /// radix from 2 to 36 (`2r1010`), floats (`1.5`, `1e10`, `1.`), and the symbolic values
+    /// `##Inf`, `##-Inf` and `##NaN`. Any of them may be signed.
+    ///
+    /// A number runs to the end of its token, so `1abc` is an invalid number rather than `1`
+    /// followed by `abc`. Ratios that don't divide evenly, `M` decimals and integers out of
+    /// the 64-bit range have no value to read into yet, and are read errors.
     fn try_read_number<'r, 'o, 'i: 'o>(
         &'r self,
         _env: PtrEnvironment,
         input: &'i str,
     ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
-        let number_parser = recognize(tuple((
-            opt(char('-')),
-            many1(one_of("0123456789")),
-            opt(tuple((char('.'), many1(one_of("0123456789"))))),
-        )));
-        let mut parser = map(number_parser, |s: &str| {
-            if s.contains('.') {
-                let float: f64 = s.parse().expect("failed to parse validated float string");
-                Arc::new(Value::float(float.into()))
-            } else {
-                Arc::new(Value::integer(
-                    s.parse().expect("failed to parse validated integer string"),
-                ))
+        let (remaining, (symbolic, token)) = alt((
+            pair(value(true, tag("##")), take_till(is_token_end)),
+            pair(
+                value(false, tag("")),
+                recognize(tuple((
+                    opt(one_of("+-")),
+                    one_of("0123456789"),
+                    take_till(is_token_end),
+                ))),
+            ),
+        ))(input)?;
+        let number = if symbolic {
+            match token {
+                "Inf" => Ok(f64::INFINITY),
+                "-Inf" => Ok(f64::NEG_INFINITY),
+                "NaN" => Ok(f64::NAN),
+                _ => Err(format!("unknown symbolic value: ##{token}")),
+            }
+            .map(|float| Value::float_ptr(float.into()))
+        } else {
+            parse_number(token)
+        };
+        match number {
+            Ok(number) => Ok((remaining, Some(number))),
+            Err(message) => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - remaining.len(),
+                    message,
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )))
+            }
+        }
+    }
+
+    fn try_read_string<'r, 'o, 'i: 'o>(
+        &'r self,
+        _env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let string_char = alt((|i| self.try_read_string_escape(i), none_of("\"\\")));
+
+        let mut parser = delimited(
+            char('"'),
+            map(many0(string_char), |chars| {
+                Arc::new(Value::string(chars.into_iter().collect()))
+            }),
+            |i| self.try_read_closing(i, '"'),
+        );
+        let (remaining, value) = self.note_unclosed(parser(input), input, "\"", '"')?;
+        Ok((remaining, Some(value)))
+    }
+
+    /// `#"pattern"` -> the compiled regex, in a [`RegexHandle`]. The pattern is taken as written,
+    /// escapes and all, except that `\"` doesn't end it.
+    fn try_read_regex<'r, 'o, 'i: 'o>(
+        &'r self,
+        _env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let (rest, _) = tag("#\"")(input)?;
+        let mut chars = rest.char_indices();
+        let end = loop {
+            match chars.next() {
+                None => return Err(nom::Err::Incomplete(nom::Needed::new(1))),
+                Some((i, '"')) => break i,
+                Some((_, '\\')) => {
+                    if chars.next().is_none() {
+                        return Err(nom::Err::Incomplete(nom::Needed::new(1)));
+                    }
+                }
+                Some(_) => {}
+            }
+        };
+        let remaining = &rest[end + 1..];
+        match RegexHandle::new(&rest[..end]) {
+            Ok(regex) => Ok((remaining, Some(Value::handle_ptr(Handle::new(regex))))),
+            Err(message) => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - remaining.len(),
+                    format!("invalid regex: {message}"),
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )))
+            }
+        }
+    }
+
+    /// `\"`, `\\`, `\n`, `\t`, `\r`, `\b` and `\f` -> the characters they stand for,
+    /// `\uXXXX` -> the character with that hex code point and `\N`, `\NN` or `\NNN` -> the one
+    /// with that octal code point, up to `\377`.
+    fn try_read_string_escape<'i>(&self, input: &'i str) -> IResult<&'i str, char> {
+        let (rest, _) = char('\\')(input)?;
+        let Some(first) = rest.chars().next() else {
+            return Err(nom::Err::Incomplete(nom::Needed::new(1)));
+        };
+        let (escaped, len) = match first {
+            '"' | '\\' => (Some(first), 1),
+            'n' => (Some('\n'), 1),
+            't' => (Some('\t'), 1),
+            'r' => (Some('\r'), 1),
+            'b' => (Some('\u{8}'), 1),
+            'f' => (Some('\u{c}'), 1),
+            'u' => {
+                let len = rest[1..]
+                    .chars()
+                    .take(4)
+                    .take_while(|c| c.is_ascii_hexdigit())
+                    .count();
+                if len < 4 && rest.len() == 1 + len {
+                    return Err(nom::Err::Incomplete(nom::Needed::new(4 - len)));
+                }
+                let code_point = u32::from_str_radix(&rest[1..1 + len], 16).ok();
+                (
+                    code_point.filter(|_| len == 4).and_then(char::from_u32),
+                    1 + len,
+                )
             }
-        });
-        let (remaining, value) = parser(input)?;
-        Ok((remaining, Some(value)))
+            '0'..='7' => {
+                let len = rest.chars().take(3).take_while(|c| c.is_digit(8)).count();
+                let code_point = u32::from_str_radix(&rest[..len], 8).ok();
+                (
+                    code_point
+                        .filter(|code_point| *code_point <= 0o377)
+                        .and_then(char::from_u32),
+                    len,
+                )
+            }
+            _ => (None, first.len_utf8()),
+        };
+        match escaped {
+            Some(c) => Ok((&rest[len..], c)),
+            None => {
+                self.fail(ReadFailure::new(
+                    input,
+                    1 + len,
+                    format!("unsupported escape sequence: \\{}", &rest[..len]),
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Escaped,
+                )))
+            }
+        }
     }
 
-    fn try_read_string<'r, 'o, 'i: 'o>(
+    /// `\c` -> the character `c`, `\newline`, `\space`, `\tab`, `\backspace`, `\formfeed`
+    /// and `\return` -> the characters they name, `\uXXXX` -> the character with that hex
+    /// code point and `\oNNN` -> the one with that octal code point, up to `\o377`.
+    fn try_read_char<'r, 'o, 'i: 'o>(
         &'r self,
         _env: PtrEnvironment,
         input: &'i str,
     ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
-        let backslash_escape = alt((
-            map(tag("\\\""), |_| '"'),
-            map(tag("\\\\"), |_| '\\'),
-            map(tag("\\n"), |_| '\n'),
-            map(tag("\\t"), |_| '\t'),
-            map(tag("\\r"), |_| '\r'),
-        ));
-
-        let string_char = alt((backslash_escape, none_of("\"\\")));
-
-        let mut parser = delimited(
-            char('"'),
-            map(many0(string_char), |chars| {
-                Arc::new(Value::string(chars.into_iter().collect()))
-            }),
-            char('"'),
-        );
-        let (remaining, value) = parser(input)?;
-        Ok((remaining, Some(value)))
+        let (rest, _) = char('\\')(input)?;
+        // the first character is taken as-is even where it would end a token, as in `\(`
+        let Some(first) = rest.chars().next() else {
+            return Err(nom::Err::Incomplete(nom::Needed::new(1)));
+        };
+        let (remaining, _) = take_till(is_token_end)(&rest[first.len_utf8()..])?;
+        let token = &rest[..rest.len() - remaining.len()];
+        match parse_char(token) {
+            Some(c) => Ok((remaining, Some(Value::char_ptr(c)))),
+            None => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - remaining.len(),
+                    format!("unsupported character: \\{token}"),
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )))
+            }
+        }
     }
 
     fn try_read_list<'r, 'o, 'i: 'o>(
@@ -256,87 +1450,14 @@ impl Reader {
         let mut parser = delimited(
             char('('),
             map(
-                cut(separated_list0(ws1, |i| self.try_read_any(env.clone(), i))),
-                |elements| {
-                    elements
-                        .into_iter()
-                        .filter_map(std::convert::identity)
-                        .collect::<Vec<PtrValue>>()
-                },
+                cut(separated_list0(ws1, |i| {
+                    self.try_read_element(env.clone(), i)
+                })),
+                |elements| elements.into_iter().flatten().collect::<Vec<PtrValue>>(),
             ),
-            preceded(ws0, char(')')),
+            preceded(ws0, |i| self.try_read_closing(i, ')')),
         );
-        let (remaining, elements) = parser(input)?;
-
-        if let Some(head) = elements
-            .first()
-            .map(PtrValue::as_ref)
-            .and_then(value::optics::preview_symbol)
-        {
-            match head {
-                Symbol::Qualified(head) => {
-                    // log::info!("Resolving qualified symbol: {}", head);
-                    if let Some(var) = env
-                        .try_get_namespace(head.namespace())
-                        .and_then(|ns| ns.try_get_var(head.name()).ok())
-                    {
-                        let is_macro = var
-                            .get_meta(&Value::keyword_unqualified_ptr("macro"))
-                            .as_ref()
-                            .map(PtrValue::as_ref)
-                            .and_then(value::optics::preview_boolean)
-                            .unwrap_or(false);
-                        if is_macro {
-                            if let Some(macro_func) = var
-                                .as_ref()
-                                .deref()
-                                .as_ref()
-                                .map(PtrValue::as_ref)
-                                .and_then(value::optics::preview_function)
-                            {
-                                let macro_ret = macro_func.invoke(
-                                    env.clone(),
-                                    EvalContext::new_empty(),
-                                    elements.into_iter().skip(1).collect(),
-                                );
-                                return Ok((remaining, Some(macro_ret)));
-                            }
-                        }
-                    }
-                }
-                Symbol::Unqualified(head) => {
-                    // log::info!("Resolving unqualified symbol: {}", head);
-                    let current_ns = env.try_get_namespace("clojure.core")
-                        .and_then(|ns| ns.try_get_handle::<PtrNamespace>("*ns*").ok())
-                        .expect("current namespace not set, expected #'clojure.core/*ns* to be bound to a Value::Handle of Arc<Namespace>");
-                    if let Some(var) = current_ns.try_get_var(head.name()).ok() {
-                        let is_macro = var
-                            .get_meta(&Value::keyword_unqualified_ptr("macro"))
-                            .as_ref()
-                            .map(PtrValue::as_ref)
-                            .and_then(value::optics::preview_boolean)
-                            .unwrap_or(false);
-                        if is_macro {
-                            if let Some(macro_func) = var
-                                .as_ref()
-                                .deref()
-                                .as_ref()
-                                .map(PtrValue::as_ref)
-                                .and_then(value::optics::preview_function)
-                            {
-                                let macro_ret = macro_func.invoke(
-                                    env.clone(),
-                                    EvalContext::new_empty(),
-                                    elements.into_iter().skip(1).collect(),
-                                );
-                                return Ok((remaining, Some(macro_ret)));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
+        let (remaining, elements) = self.note_unclosed(parser(input), input, "(", ')')?;
         Ok((remaining, Some(Value::list_ptr(List::from(elements)))))
     }
 
@@ -348,17 +1469,14 @@ impl Reader {
         let mut parser = delimited(
             char('['),
             map(
-                cut(separated_list0(ws1, |i| self.try_read_any(env.clone(), i))),
-                |elements| {
-                    elements
-                        .into_iter()
-                        .filter_map(std::convert::identity)
-                        .collect::<Vec<PtrValue>>()
-                },
+                cut(separated_list0(ws1, |i| {
+                    self.try_read_element(env.clone(), i)
+                })),
+                |elements| elements.into_iter().flatten().collect::<Vec<PtrValue>>(),
             ),
-            preceded(ws0, char(']')),
+            preceded(ws0, |i| self.try_read_closing(i, ']')),
         );
-        let (remaining, elements) = parser(input)?;
+        let (remaining, elements) = self.note_unclosed(parser(input), input, "[", ']')?;
         Ok((remaining, Some(Value::vector_ptr(Vector::from(elements)))))
     }
 
@@ -372,18 +1490,15 @@ impl Reader {
             delimited(
                 ws0,
                 map(
-                    cut(separated_list0(ws1, |i| self.try_read_any(env.clone(), i))),
-                    |elements| {
-                        elements
-                            .into_iter()
-                            .filter_map(std::convert::identity)
-                            .collect::<Vec<PtrValue>>()
-                    },
+                    cut(separated_list0(ws1, |i| {
+                        self.try_read_element(env.clone(), i)
+                    })),
+                    |elements| elements.into_iter().flatten().collect::<Vec<PtrValue>>(),
                 ),
-                preceded(ws0, char('}')),
+                preceded(ws0, |i| self.try_read_closing(i, '}')),
             ),
         );
-        let (remaining, elements) = parser(input)?;
+        let (remaining, elements) = self.note_unclosed(parser(input), input, "#{", '}')?;
         Ok((remaining, Some(Value::set_ptr(Set::new(elements)))))
     }
 
@@ -395,32 +1510,105 @@ impl Reader {
         let mut parser = delimited(
             char('{'),
             map(
-                separated_list0(
-                    ws1,
-                    separated_pair(
-                        |i| self.try_read_any(env.clone(), i),
-                        ws1,
-                        |i| self.try_read_any(env.clone(), i),
-                    ),
-                ),
-                // |pairs| Arc::new(Value::map_from(pairs)),
-                |pairs| {
-                    pairs
-                        .into_iter()
-                        //  .filter_map(std::convert::identity)
-                        .filter_map(|(k, v)| match (k, v) {
-                            (Some(k), Some(v)) => Some((k, v)),
-                            _ => None,
-                        })
-                        .collect::<Vec<(PtrValue, PtrValue)>>()
-                },
+                cut(separated_list0(ws1, |i| {
+                    self.try_read_element(env.clone(), i)
+                })),
+                |elements| elements.into_iter().flatten().collect::<Vec<PtrValue>>(),
             ),
-            preceded(ws0, char('}')),
+            preceded(ws0, |i| self.try_read_closing(i, '}')),
         );
-        let (remaining, entries) = parser(input)?;
+        // keys and values are read as a flat sequence, so that `#_` can discard either
+        let (remaining, elements) = self.note_unclosed(parser(input), input, "{", '}')?;
+        if !elements.len().is_multiple_of(2) {
+            self.fail(ReadFailure::new(
+                input,
+                input.len() - remaining.len(),
+                "map literal must contain an even number of forms".to_owned(),
+                None,
+            ));
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+        let entries = elements
+            .chunks(2)
+            .map(|pair| (pair[0].to_owned(), pair[1].to_owned()))
+            .collect::<Vec<(PtrValue, PtrValue)>>();
         Ok((remaining, Some(Value::map_ptr(Map::new(entries)))))
     }
 
+    /// `#tag form` -> what the reader for `tag` makes of `form`. The reader is the function
+    /// for `tag` in the `clojure.core/*data-readers*` map if there is one, otherwise this
+    /// reader's own (see [`Reader::with_data_reader`]). Without either, the tag is unknown
+    /// (see [`UnknownTags`]).
+    fn try_read_tagged<'r, 'o, 'i: 'o>(
+        &'r self,
+        env: PtrEnvironment,
+        input: &'i str,
+    ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        let (rest, _) = char('#')(input)?;
+        // a tag is a symbol, and tags start with a letter to leave room for other `#` syntax
+        if !rest.starts_with(|c: char| c.is_alphabetic()) {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                ErrorKind::Alpha,
+            )));
+        }
+        let (rest, tag) = self.try_read_symbol(env.clone(), rest)?;
+        let Some(Value::Symbol(tag, _)) = tag.as_deref() else {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        };
+        let (remaining, form) = self.try_read_present(env.clone(), rest)?;
+        // a form that is only read to be skipped needn't have a reader for its tag
+        if self.suppress_read.get() {
+            return Ok((
+                remaining,
+                Some(Value::tagged_ptr(TaggedLiteral::new(tag.to_owned(), form))),
+            ));
+        }
+
+        let bound_reader = env
+            .try_get_namespace("clojure.core")
+            .and_then(|ns| ns.try_get_value("*data-readers*").ok())
+            .and_then(|readers| {
+                readers
+                    .preview_map_ref()
+                    .and_then(|readers| readers.get(&Value::symbol_ptr(tag.clone())))
+            });
+        let read = match (bound_reader, self.data_readers.get(tag)) {
+            (Some(reader), _) => {
+                core::try_apply(env.clone(), EvalContext::new_empty(), reader, vec![form])
+                    .map_err(|err| err.to_string())
+            }
+            (None, Some(reader)) => reader(form),
+            (None, None) => match self.unknown_tags {
+                UnknownTags::Error => Err(format!("no reader function for tag {tag}")),
+                UnknownTags::TaggedLiteral => {
+                    Ok(Value::tagged_ptr(TaggedLiteral::new(tag.to_owned(), form)))
+                }
+            },
+        };
+        match read {
+            Ok(value) => Ok((remaining, Some(value))),
+            Err(message) => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - remaining.len(),
+                    message,
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )))
+            }
+        }
+    }
+
     fn try_read_keyword<'r, 'o, 'i: 'o>(
         &'r self,
         _env: PtrEnvironment,
@@ -428,7 +1616,7 @@ impl Reader {
     ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
         // Charset without : (prefix) and / (namespace separator)
         let keyword_charset =
-            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!@#$%^&*_+-=~<>.";
+            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!@#$%^&*_+-=~<>.?";
         let build_keyword_chars = || recognize(many1(one_of(keyword_charset)));
 
         // Consume the initial `:` or `::`
@@ -478,8 +1666,25 @@ impl Reader {
         _env: PtrEnvironment,
         input: &'i str,
     ) -> Result<(&'o str, Option<PtrValue>), nom::Err<nom::error::Error<&'i str>>> {
+        // `#` starts the dispatch forms, which are all tried before symbols, so what is left is
+        // a dispatch form that doesn't exist, such as `#!` after the start of the source
+        if let Some(rest) = input.strip_prefix('#') {
+            let Some(dispatch) = rest.chars().next() else {
+                return Err(nom::Err::Incomplete(nom::Needed::new(1)));
+            };
+            self.fail(ReadFailure::new(
+                input,
+                1 + dispatch.len_utf8(),
+                format!("no dispatch macro for: #{dispatch}"),
+                None,
+            ));
+            return Err(nom::Err::Failure(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
         let symbol_charset =
-            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!@#$%^&*_+-=~<>.";
+            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!@#$%^&*_+-=~<>.?";
         let build_symbol_chars = || recognize(many1(one_of(symbol_charset)));
 
         // Try to parse a qualified symbol (namespace/name), falling back to unqualified
@@ -501,15 +1706,316 @@ impl Reader {
 
         let mut parser = alt((qualified_parser, unqualified_parser, slash_parser));
         let (remaining, value) = parser(input)?;
-        Ok((remaining, Some(value)))
+        let arg = match value.as_ref() {
+            Value::Symbol(Symbol::Unqualified(symbol), _) => self.fn_literal_arg(symbol.name()),
+            _ => None,
+        };
+        match arg {
+            None => Ok((remaining, Some(value))),
+            Some(Ok(param)) => Ok((remaining, Some(Value::symbol_ptr(param)))),
+            Some(Err(message)) => {
+                self.fail(ReadFailure::new(
+                    input,
+                    input.len() - remaining.len(),
+                    message,
+                    None,
+                ));
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    ErrorKind::Verify,
+                )))
+            }
+        }
+    }
+}
+
+/// A fresh parameter for a `#(...)` function literal, e.g. `p1__N#` for `base` `p1`
+fn fn_literal_param(base: &str) -> Symbol {
+    let generated = Symbol::gensym(&format!("{base}__"));
+    Symbol::new_unqualified(&format!("{}#", generated.name()))
+}
+
+/// Expand a syntax-quoted `form` into code that builds it when evaluated:
+/// - Unqualified symbols are qualified with the current namespace, except special forms (see
+///   [`core::is_special_form`]). `foo#` becomes an auto-gensym `foo__N__auto__`, the same one
+///   for every `foo#` in `gensyms` (i.e. in one syntax-quote).
+/// - `(clojure.core/unquote x)` becomes `x`.
+/// - Lists, vectors and sets are rebuilt from their syntax-quoted elements, splicing in the
+///   elements of `x` for each `(clojure.core/unquote-splicing x)`. Maps are rebuilt from
+///   their syntax-quoted keys and values.
+/// - Other values evaluate to themselves and are left as-is.
+///
+/// Returns `None` for `unquote-splicing` outside a list, vector or set.
+fn syntax_quote(
+    env: &PtrEnvironment,
+    form: &PtrValue,
+    gensyms: &mut HashMap<String, Symbol>,
+) -> Option<PtrValue> {
+    let quote =
+        |form: PtrValue| Value::new_list_ptr(vec![Value::symbol_unqualified_ptr("quote"), form]);
+    let core_call = |name: &str, args: Vec<PtrValue>| {
+        let mut elements = vec![Value::symbol_qualified_ptr("clojure.core", name)];
+        elements.extend(args);
+        Value::new_list_ptr(elements)
+    };
+    match form.as_ref() {
+        Value::Symbol(Symbol::Unqualified(symbol), _) => {
+            let name = symbol.name();
+            let symbol = match name.strip_suffix('#') {
+                Some(base) if !base.is_empty() => gensyms
+                    .entry(base.to_owned())
+                    .or_insert_with(|| {
+                        let generated = Symbol::gensym(&format!("{base}__"));
+                        Symbol::new_unqualified(&format!("{}__auto__", generated.name()))
+                    })
+                    .to_owned(),
+                _ if core::is_special_form(name) => Symbol::new_unqualified(name),
+                _ => match env.try_get_current_namespace() {
+                    Ok(ns) => Symbol::new_qualified(ns.name_str(), name),
+                    Err(_) => Symbol::new_unqualified(name),
+                },
+            };
+            Some(quote(Value::symbol_ptr(symbol)))
+        }
+        Value::Symbol(Symbol::Qualified(_), _) => Some(quote(form.to_owned())),
+        Value::List(list, _) => {
+            if let Some(unquoted) = unquoted(form, "unquote") {
+                return Some(unquoted);
+            }
+            if unquoted(form, "unquote-splicing").is_some() {
+                return None;
+            }
+            let elements: Vec<PtrValue> = list.iter().cloned().collect();
+            Some(match syntax_quote_elements(env, &elements, gensyms)? {
+                SyntaxQuotedElements::Plain(elements) => core_call("list", elements),
+                SyntaxQuotedElements::Spliced(parts) => {
+                    core_call("seq", vec![core_call("concat", parts)])
+                }
+            })
+        }
+        Value::Vector(vector, _) => {
+            let elements: Vec<PtrValue> = vector.iter().cloned().collect();
+            Some(match syntax_quote_elements(env, &elements, gensyms)? {
+                SyntaxQuotedElements::Plain(elements) => Value::new_vector_ptr(elements),
+                SyntaxQuotedElements::Spliced(parts) => {
+                    core_call("vec", vec![core_call("concat", parts)])
+                }
+            })
+        }
+        Value::Set(set, _) => {
+            let elements: Vec<PtrValue> = set.iter().cloned().collect();
+            Some(match syntax_quote_elements(env, &elements, gensyms)? {
+                SyntaxQuotedElements::Plain(elements) => Value::new_set_ptr(elements),
+                SyntaxQuotedElements::Spliced(parts) => {
+                    core_call("set", vec![core_call("concat", parts)])
+                }
+            })
+        }
+        Value::Map(map, _) => {
+            let mut entries = vec![];
+            for (k, v) in map.iter() {
+                entries.push((
+                    syntax_quote(env, k, gensyms)?,
+                    syntax_quote(env, v, gensyms)?,
+                ));
+            }
+            Some(Value::new_map_ptr(entries))
+        }
+        Value::Nil(_)
+        | Value::Boolean(_, _)
+        | Value::Integer(_, _)
+        | Value::Float(_, _)
+        | Value::String(_, _)
+        | Value::Keyword(_, _) => Some(form.to_owned()),
+        _ => Some(quote(form.to_owned())),
+    }
+}
+
+/// The syntax-quoted elements of a collection.
+enum SyntaxQuotedElements {
+    /// No element was spliced: the syntax-quoted elements
+    Plain(Vec<PtrValue>),
+    /// Some element was spliced: the parts to concatenate, a `(clojure.core/list x)` for each
+    /// element and the spliced collection for each `~@`
+    Spliced(Vec<PtrValue>),
+}
+
+fn syntax_quote_elements(
+    env: &PtrEnvironment,
+    elements: &[PtrValue],
+    gensyms: &mut HashMap<String, Symbol>,
+) -> Option<SyntaxQuotedElements> {
+    let mut quoted = vec![];
+    let mut parts = vec![];
+    let mut spliced = false;
+    for element in elements {
+        if let Some(splice) = unquoted(element, "unquote-splicing") {
+            spliced = true;
+            parts.push(splice);
+        } else {
+            let element = syntax_quote(env, element, gensyms)?;
+            parts.push(Value::new_list_ptr(vec![
+                Value::symbol_qualified_ptr("clojure.core", "list"),
+                element.clone(),
+            ]));
+            quoted.push(element);
+        }
+    }
+    Some(if spliced {
+        SyntaxQuotedElements::Spliced(parts)
+    } else {
+        SyntaxQuotedElements::Plain(quoted)
+    })
+}
+
+/// If `form` is `(clojure.core/<name> x)`, return `x`.
+fn unquoted(form: &PtrValue, name: &str) -> Option<PtrValue> {
+    let Value::List(list, _) = form.as_ref() else {
+        return None;
+    };
+    let head = list.get_first()?;
+    let Value::Symbol(Symbol::Qualified(head), _) = head.as_ref() else {
+        return None;
+    };
+    (list.len() == 2 && head.namespace() == "clojure.core" && head.name() == name)
+        .then(|| list.get_second())
+        .flatten()
+}
+
+/// Parses a number `token` as read by [`Reader::try_read_number`], or says why it isn't one.
+fn parse_number(token: &str) -> Result<PtrValue, String> {
+    let invalid = || format!("invalid number: {token}");
+    let (negative, unsigned) = match token.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let is_digits = |s: &str, radix: u32| !s.is_empty() && s.chars().all(|c| c.is_digit(radix));
+    let signed_integer = |digits: &str, radix: u32, negative: bool| {
+        if !is_digits(digits, radix) {
+            return Err(invalid());
+        }
+        let magnitude = i128::from_str_radix(digits, radix).map_err(|_| invalid())?;
+        i64::try_from(if negative { -magnitude } else { magnitude })
+            .map_err(|_| format!("integer out of range: {token} (big integers aren't supported)"))
+    };
+    let integer = |digits: &str, radix: u32| signed_integer(digits, radix, negative);
+
+    if let Some((numerator, denominator)) = unsigned.split_once('/') {
+        let numerator = integer(numerator, 10)?;
+        let denominator = signed_integer(denominator, 10, false)?;
+        return match denominator {
+            0 => Err(format!("divide by zero: {token}")),
+            _ if numerator % denominator == 0 => Ok(Value::integer_ptr(numerator / denominator)),
+            _ => Err(format!("ratios aren't supported: {token}")),
+        };
+    }
+    if let Some(hex) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    {
+        return integer(hex.strip_suffix('N').unwrap_or(hex), 16).map(Value::integer_ptr);
+    }
+    if let Some((radix, digits)) = unsigned.split_once(['r', 'R']) {
+        return match radix.parse::<u32>() {
+            Ok(radix_value @ 2..=36) if is_digits(radix, 10) && !radix.starts_with('0') => {
+                integer(digits, radix_value).map(Value::integer_ptr)
+            }
+            _ => Err(format!("invalid radix: {token}")),
+        };
+    }
+    if let Some(decimal) = unsigned.strip_suffix('M') {
+        return if decimal
+            .chars()
+            .all(|c| c.is_ascii_digit() || ".eE+-".contains(c))
+        {
+            Err(format!("big decimals aren't supported: {token}"))
+        } else {
+            Err(invalid())
+        };
+    }
+    let unsigned = unsigned.strip_suffix('N').unwrap_or(unsigned);
+    if is_digits(unsigned, 10) {
+        return match unsigned.strip_prefix('0') {
+            Some(octal) if !octal.is_empty() => integer(octal, 8),
+            _ => integer(unsigned, 10),
+        }
+        .map(Value::integer_ptr);
+    }
+    if token.ends_with('N') {
+        return Err(invalid());
+    }
+    // `digits(.digits*)?([eE][+-]?digits)?`
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (unsigned, None),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exponent_digits = exponent.map(|e| e.strip_prefix(['+', '-']).unwrap_or(e));
+    if is_digits(whole, 10)
+        && (fraction.is_empty() || is_digits(fraction, 10))
+        && exponent_digits.is_none_or(|digits| is_digits(digits, 10))
+    {
+        let float: f64 = token.parse().map_err(|_| invalid())?;
+        return Ok(Value::float_ptr(float.into()));
     }
+    Err(invalid())
+}
+
+/// Parses the `token` after the `\` of a character literal, as read by
+/// [`Reader::try_read_char`].
+fn parse_char(token: &str) -> Option<char> {
+    let mut chars = token.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(c);
+    }
+    if let Some((_, c)) = CHAR_NAMES.iter().find(|(name, _)| *name == token) {
+        return Some(*c);
+    }
+    let code_point = |digits: &str, radix: u32, max_len: usize| {
+        (!digits.is_empty() && digits.len() <= max_len && digits.chars().all(|c| c.is_digit(radix)))
+            .then(|| u32::from_str_radix(digits, radix).ok())
+            .flatten()
+    };
+    if let Some(hex) = token.strip_prefix('u') {
+        return code_point(hex, 16, 4)
+            .filter(|_| hex.len() == 4)
+            .and_then(char::from_u32);
+    }
+    if let Some(octal) = token.strip_prefix('o') {
+        return code_point(octal, 8, 3)
+            .filter(|code_point| *code_point <= 0o377)
+            .and_then(char::from_u32);
+    }
+    None
+}
+
+/// Whether `c` ends a token such as a number: whitespace, a comma, or a character that starts
+/// or ends another form
+fn is_token_end(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            ',' | '"' | ';' | '@' | '^' | '`' | '~' | '(' | ')' | '[' | ']' | '{' | '}' | '\\'
+        )
 }
 
 fn ws0(input: &str) -> IResult<&str, ()> {
-    value((), many0(one_of(", \t\r\n")))(input)
+    value((), many0(alt((value((), one_of(", \t\r\n")), comment))))(input)
 }
 fn ws1(input: &str) -> IResult<&str, ()> {
-    value((), many1(one_of(", \t\r\n")))(input)
+    value((), many1(alt((value((), one_of(", \t\r\n")), comment))))(input)
+}
+/// A `;` line comment, up to the end of the line
+fn comment(input: &str) -> IResult<&str, ()> {
+    value((), preceded(tag(";"), take_till(|c| c == '\n')))(input)
+}
+
+/// `input` without a `#!` shebang line at its start, if it has one
+fn skip_shebang(input: &str) -> &str {
+    match input.strip_prefix("#!") {
+        Some(rest) => &rest[rest.find('\n').unwrap_or(rest.len())..],
+        None => input,
+    }
 }
 
 impl TryRead for Reader {
@@ -521,41 +2027,43 @@ impl TryRead for Reader {
         &'r self,
         env: PtrEnvironment,
         input: &'i str,
-    ) -> Result<(&'o str, Option<PtrValue>), AnomalyMap> {
-        self.try_read_any(env, input).map_err(move |e| {
-            let mut anomaly = AnomalyMap::new_empty();
+    ) -> Result<(&'o str, Option<PtrValue>), ReadError> {
+        let origin = match &self.source {
+            Some(source) if source.text.ends_with(input) => source.clone(),
+            _ => Arc::new(SourceText::new(input)),
+        };
+        // a shebang line is only allowed as the first line of the source
+        let mut input = if origin.text.len() == input.len() {
+            skip_shebang(input)
+        } else {
+            input
+        };
+        *self.origin.borrow_mut() = Some(origin);
+        *self.failure.borrow_mut() = None;
+        // skip discarded forms, so that nothing is read only at the end of the input
+        let result = loop {
+            if let Ok(("", _)) = ws0(input) {
+                break Ok(("", None));
+            }
+            match self.try_read_any(env.clone(), input) {
+                Ok((remaining, None)) => input = remaining,
+                result => break result,
+            }
+        };
+        result.map_err(move |e| {
+            let failure = self.failure.take();
             match e {
-                nom::Err::Incomplete(nom::Needed::Unknown) => {
-                    anomaly
-                        .set_category(KeywordUnqualified::new("incomplete-input"))
-                        .set_message(
-                            "reader needs an additional unknown number of bytes of input"
-                                .to_owned(),
-                        );
-                }
-                nom::Err::Incomplete(nom::Needed::Size(size)) => {
-                    anomaly
-                        .set_category(KeywordUnqualified::new("incomplete-input"))
-                        .set_message(format!(
-                            "reader needs an additional {size} byte(s) of input"
-                        ));
-                }
+                nom::Err::Incomplete(_) => ReadError::Incomplete(self.failure_anomaly(
+                    "incomplete-input",
+                    failure.unwrap_or_else(|| ReadFailure::unexpected("")),
+                )),
                 nom::Err::Error(e) | nom::Err::Failure(e) => {
-                    anomaly
-                        .set_category(KeywordUnqualified::new("erroneous-input"))
-                        .set_message(match e.input.trim() {
-                            "(" => "unclosed list".to_owned(),
-                            "[" => "unclosed vector".to_owned(),
-                            "#{" => "unclosed set".to_owned(),
-                            "{" => "unclosed map".to_owned(),
-                            ")" => "unopened list".to_owned(),
-                            "]" => "unopened vector".to_owned(),
-                            "}" => "unopened set or map".to_owned(),
-                            _input => format!("{e:?}"),
-                        });
+                    ReadError::Anomaly(self.failure_anomaly(
+                        "erroneous-input",
+                        failure.unwrap_or_else(|| ReadFailure::unexpected(e.input)),
+                    ))
                 }
             }
-            anomaly
         })
     }
 
@@ -639,3 +2147,468 @@ impl TryRead for Reader {
     //     todo!("try_read_map")
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_env() -> PtrEnvironment {
+        let mut env_builder = Environment::builder();
+        env_builder.set_current_namespace_var("clojure.core", "*ns*");
+        let env = env_builder.build_ptr();
+        let clojure_core = Namespace::new_empty_ptr("clojure.core");
+        clojure_core.bind_value("*ns*", Value::handle(Handle::new(clojure_core.clone())));
+        env.insert_namespace(clojure_core);
+        env
+    }
+
+    fn read_one(input: &str) -> PtrValue {
+        Reader::new()
+            .try_read(create_env(), input)
+            .expect("failed to read")
+            .1
+            .expect("no value read")
+    }
+
+    fn read_error(input: &str) -> ReadError {
+        Reader::new()
+            .try_read(create_env(), input)
+            .expect_err("expected a read error")
+    }
+
+    // number literals
+
+    #[test]
+    fn integer_literals_in_every_radix() {
+        for (input, expected) in [
+            ("+5", 5),
+            ("-5", -5),
+            ("0", 0),
+            ("0xFF", 255),
+            ("-0x10", -16),
+            ("017", 15),
+            ("2r1010", 10),
+            ("36rZZ", 1295),
+            ("1N", 1),
+            ("4/2", 2),
+            ("-9223372036854775808", i64::MIN),
+        ] {
+            assert_eq!(
+                read_one(input),
+                Value::integer_ptr(expected),
+                "reading {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn float_literals() {
+        for (input, expected) in [
+            ("1e10", 1e10),
+            ("1.5", 1.5),
+            ("-2.5e-3", -2.5e-3),
+            ("1.", 1.0),
+            ("+1E2", 100.0),
+        ] {
+            assert_eq!(
+                read_one(input),
+                Value::float_ptr(expected.into()),
+                "reading {input}"
+            );
+        }
+        assert_eq!(read_one("##Inf"), Value::float_ptr(f64::INFINITY.into()));
+        assert_eq!(
+            read_one("##-Inf"),
+            Value::float_ptr(f64::NEG_INFINITY.into())
+        );
+        assert!(
+            value::optics::preview_float(&read_one("##NaN"))
+                .is_some_and(|float| float.as_f64().is_nan())
+        );
+        assert_eq!(
+            read_one("[##Inf ##-Inf ##NaN]").to_string(),
+            "[##Inf ##-Inf ##NaN]"
+        );
+    }
+
+    #[test]
+    fn number_runs_to_end_of_token() {
+        assert_eq!(
+            read_one("(+ 1 2)"),
+            Value::new_list_ptr(vec![
+                Value::symbol_unqualified_ptr("+"),
+                Value::integer_ptr(1),
+                Value::integer_ptr(2),
+            ])
+        );
+        assert_eq!(read_one("[1,2]"), read_one("[1 2]"));
+        assert!(read_one(".5").is_symbol());
+        assert!(read_one("-").is_symbol());
+        assert_eq!(
+            read_error("1abc").to_string(),
+            "1:1: invalid number: 1abc\n1abc\n^^^^"
+        );
+    }
+
+    #[test]
+    fn unrepresentable_numbers_are_read_errors() {
+        for (input, message) in [
+            ("1/3", "ratios aren't supported: 1/3"),
+            ("1/0", "divide by zero: 1/0"),
+            ("1.5M", "big decimals aren't supported: 1.5M"),
+            (
+                "9223372036854775808",
+                "integer out of range: 9223372036854775808 (big integers aren't supported)",
+            ),
+            ("08", "invalid number: 08"),
+            ("37r1", "invalid radix: 37r1"),
+            ("1.5N", "invalid number: 1.5N"),
+            ("##Foo", "unknown symbolic value: ##Foo"),
+        ] {
+            assert_eq!(
+                read_error(input).to_anomaly_map().get_message(),
+                message,
+                "reading {input}"
+            );
+        }
+    }
+
+    // characters
+
+    #[test]
+    fn character_literals() {
+        for (input, expected) in [
+            (r"\a", 'a'),
+            (r"\newline", '\n'),
+            (r"\space", ' '),
+            (r"\tab", '\t'),
+            (r"\return", '\r'),
+            (r"\u03a9", 'Ω'),
+            (r"\o101", 'A'),
+            (r"\(", '('),
+            (r"\\", '\\'),
+        ] {
+            assert_eq!(
+                read_one(input),
+                Value::char_ptr(expected),
+                "reading {input}"
+            );
+        }
+        assert_eq!(
+            read_one(r"[\( \) \a]"),
+            Value::vector_ptr(
+                vec![
+                    Value::char_ptr('('),
+                    Value::char_ptr(')'),
+                    Value::char_ptr('a')
+                ]
+                .into()
+            )
+        );
+    }
+
+    #[test]
+    fn characters_print_readably() {
+        for input in [
+            r"\a",
+            r"\newline",
+            r"\space",
+            r"\tab",
+            r"\backspace",
+            r"\formfeed",
+            r"\return",
+            r"\Ω",
+            r"\u0000",
+        ] {
+            let value = read_one(input);
+            assert_eq!(value.to_string(), input);
+            assert_eq!(read_one(&value.to_string()), value);
+        }
+    }
+
+    #[test]
+    fn unsupported_characters_are_read_errors() {
+        for input in [r"\abc", r"\u12", r"\o400", r"\uD800"] {
+            assert_eq!(
+                read_error(input).to_anomaly_map().get_message(),
+                format!("unsupported character: {input}"),
+                "reading {input}"
+            );
+        }
+        assert!(read_error(r"\").is_incomplete());
+    }
+
+    // strings
+
+    #[test]
+    fn string_escapes() {
+        for (input, expected) in [
+            (r#""a\"b""#, "a\"b"),
+            (r#""a\\b""#, "a\\b"),
+            (r#""\n\t\r\b\f""#, "\n\t\r\u{8}\u{c}"),
+            (r#""\u03a9\u00e9""#, "Ωé"),
+            (r#""\101\0\12x\377""#, "A\0\nx\u{ff}"),
+            (r#""\1234""#, "S4"),
+        ] {
+            assert_eq!(
+                read_one(input),
+                Value::string_ptr(expected.into()),
+                "reading {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn unsupported_string_escapes_are_read_errors() {
+        for (input, message) in [
+            (r#""\q""#, r"unsupported escape sequence: \q"),
+            (r#""\u12g4""#, r"unsupported escape sequence: \u12"),
+            (r#""\uD800""#, r"unsupported escape sequence: \uD800"),
+            (r#""\400""#, r"unsupported escape sequence: \400"),
+        ] {
+            assert_eq!(
+                read_error(input).to_anomaly_map().get_message(),
+                message,
+                "reading {input}"
+            );
+        }
+        assert_eq!(
+            read_error(r#""ab\q""#).to_string(),
+            "1:4: unsupported escape sequence: \\q\n\"ab\\q\"\n   ^^"
+        );
+        assert!(read_error(r#""ab\"#).is_incomplete());
+        assert!(read_error(r#""ab\u00"#).is_incomplete());
+    }
+
+    #[test]
+    fn strings_print_readably() {
+        let string = Value::string_ptr(
+            "quote \" backslash \\ newline \n tab \t return \r bell \u{7} Ω".into(),
+        );
+        assert_eq!(
+            string.to_string(),
+            r#""quote \" backslash \\ newline \n tab \t return \r bell \u0007 Ω""#
+        );
+        assert_eq!(read_one(&string.to_string()), string);
+    }
+
+    #[test]
+    fn printed_values_read_back_equal() {
+        for input in [
+            r#""a\"b\\c\nd""#,
+            r#"[1 -2 1.5 ##Inf "x\ty" \a \newline :k :ns/k sym ns/sym nil true]"#,
+            r#"{:a "\u0001" "b" (1 [2 #{3}])}"#,
+            r#"("\b\f" \" \\)"#,
+        ] {
+            let value = read_one(input);
+            assert_eq!(
+                read_one(&value.to_string()),
+                value,
+                "round-tripping {input}"
+            );
+        }
+    }
+
+    // source positions
+
+    fn read_position(input: &str) -> SourcePosition {
+        let form = read_one(input);
+        SourcePosition::from_value(&form)
+            .unwrap_or_else(|| panic!("expected a position on: {}", form))
+    }
+
+    #[test]
+    fn symbols_and_collections_carry_positions() {
+        let position = read_position("hello");
+        assert_eq!(
+            (
+                position.line,
+                position.column,
+                position.end_line,
+                position.end_column
+            ),
+            (1, 1, 1, 6)
+        );
+        assert_eq!(position.file, None);
+        let position = read_position("  [1\n 2]");
+        assert_eq!(
+            (
+                position.line,
+                position.column,
+                position.end_line,
+                position.end_column
+            ),
+            (1, 3, 2, 4)
+        );
+        let form = read_one("(a\n  b)");
+        let b = form
+            .view_list_ref()
+            .get_second()
+            .expect("expected a second element");
+        let position = SourcePosition::from_value(&b).expect("expected a position");
+        assert_eq!((position.line, position.column), (2, 3));
+        assert_eq!(SourcePosition::from_value(&read_one("42")), None);
+    }
+
+    #[test]
+    fn reader_with_source_counts_from_start_of_source() {
+        let env = create_env();
+        let source = "(first)\n\n  (second)";
+        let reader = Reader::with_source(source, Some("forms.clj".to_owned()));
+        let (remaining, _) = reader
+            .try_read(env.clone(), source)
+            .expect("failed to read");
+        let (_, second) = reader.try_read(env, remaining).expect("failed to read");
+        let position = SourcePosition::from_value(&second.expect("expected a form"))
+            .expect("expected a position");
+        assert_eq!((position.line, position.column), (3, 3));
+        assert_eq!(position.file.as_deref(), Some("forms.clj"));
+        assert_eq!(position.to_string(), "forms.clj:3:3");
+    }
+
+    // comments
+
+    #[test]
+    fn line_comments_are_skipped() {
+        assert_eq!(
+            read_one("; leading\n(+ 1 ; inline\n 2) ; trailing"),
+            read_one("(+ 1 2)")
+        );
+        assert_eq!(read_one("[1;no space\n2]"), read_one("[1 2]"));
+    }
+
+    #[test]
+    fn discard_form_is_skipped() {
+        assert_eq!(read_one("[1 #_2 3]"), read_one("[1 3]"));
+        assert_eq!(read_one("[1 #_ #_ 2 3 4]"), read_one("[1 4]"));
+        assert_eq!(read_one("(+ 1 #_(throw :unread))"), read_one("(+ 1)"));
+        assert_eq!(read_one("{:a 1 #_:b #_2}"), read_one("{:a 1}"));
+        assert_eq!(read_one("#_ :discarded :kept"), read_one(":kept"));
+        assert_eq!(read_one("#{1 #_2}"), read_one("#{1}"));
+    }
+
+    #[test]
+    fn read_returns_nothing_for_only_comments() {
+        for input in ["  ; just a comment", "#_ :discarded ; and a comment\n"] {
+            let (_, form) = Reader::new()
+                .try_read(create_env(), input)
+                .expect("failed to read");
+            assert_eq!(form, None, "reading {input:?}");
+        }
+    }
+
+    #[test]
+    fn shebang_line_is_skipped() {
+        assert_eq!(
+            read_one("#!/usr/bin/env jinme eval-file\n(+ 1 2)"),
+            read_one("(+ 1 2)")
+        );
+    }
+
+    #[test]
+    fn shebang_is_only_allowed_at_the_start_of_the_source() {
+        assert_eq!(
+            read_error("(a #!b)").to_anomaly_map().get_message(),
+            "no dispatch macro for: #!"
+        );
+        let source = "(ok)\n#!/usr/bin/env jinme\n";
+        let reader = Reader::with_source(source, Some("forms.clj".to_owned()));
+        let (rest, _) = reader
+            .try_read(create_env(), source)
+            .expect("failed to read");
+        assert!(reader.try_read(create_env(), rest).is_err());
+    }
+
+    // reader conditionals
+
+    fn read_cljc(input: &str) -> Result<Option<PtrValue>, ReadError> {
+        Reader::with_source(input, Some("shared.cljc".to_owned()))
+            .try_read(create_env(), input)
+            .map(|(_, value)| value)
+    }
+
+    #[test]
+    fn reader_conditionals_pick_the_jinme_form() {
+        for (input, expected) in [
+            ("#?(:clj 1 :jinme 2 :default 3)", "2"),
+            ("#?(:clj 1 :default 3)", "3"),
+            ("#?(:default 3 :jinme 2)", "3"),
+            ("#?( :clj 1\n :jinme [a b] )", "[a b]"),
+            ("[0 #?(:clj 1) 2]", "[0 2]"),
+            ("(list #?@(:clj [1 2] :jinme [3 4]) 5)", "(list 3 4 5)"),
+            ("{:a #?@(:jinme (1 :b 2))}", "{:a 1 :b 2}"),
+            ("#{#?@(:clj [1] :jinme [])}", "#{}"),
+        ] {
+            assert_eq!(
+                read_cljc(input).unwrap(),
+                Some(read_one(expected)),
+                "reading {input}"
+            );
+        }
+        assert_eq!(read_cljc("#?(:clj 1)").unwrap(), None);
+    }
+
+    #[test]
+    fn unpicked_reader_conditional_forms_need_no_tag_readers() {
+        assert_eq!(
+            read_cljc("#?(:cljs #js {:a 1} :jinme :ok)").unwrap(),
+            Some(read_one(":ok"))
+        );
+    }
+
+    #[test]
+    fn reader_conditional_features_are_configurable() {
+        let reader = Reader::new()
+            .with_reader_conditionals(ReaderConditionals::Allow)
+            .with_features([Keyword::new_unqualified("clj")]);
+        let value = reader
+            .try_read(create_env(), "#?(:jinme 1 :clj 2)")
+            .unwrap()
+            .1;
+        assert_eq!(value, Some(read_one("2")));
+    }
+
+    #[test]
+    fn reader_conditionals_are_only_allowed_in_cljc() {
+        assert_eq!(
+            read_error("#?(:jinme 1)").to_anomaly_map().get_message(),
+            "reader conditionals are only allowed when reading .cljc files"
+        );
+        let reader = Reader::with_source("#?(:jinme 1)", Some("forms.clj".to_owned()));
+        assert!(reader.try_read(create_env(), "#?(:jinme 1)").is_err());
+    }
+
+    #[test]
+    fn invalid_reader_conditionals_are_read_errors() {
+        for (input, expected) in [
+            (
+                "#?@(:jinme [1])",
+                "reader conditional splicing is not allowed at the top level",
+            ),
+            (
+                "#?(:jinme)",
+                "reader conditional requires an even number of forms",
+            ),
+            (
+                "#?(jinme 1)",
+                "reader conditional feature must be a keyword, but got: jinme",
+            ),
+            ("#?[:jinme 1]", "reader conditional body must be a list"),
+            (
+                "[#?@(:jinme 1)]",
+                "spliced form in reader conditional must be a list or vector, but got: 1",
+            ),
+        ] {
+            let err = read_cljc(input).expect_err("expected a read error");
+            assert_eq!(
+                err.to_anomaly_map().get_message(),
+                expected,
+                "reading {input}"
+            );
+        }
+        assert!(matches!(
+            read_cljc("#?(:jinme 1"),
+            Err(ReadError::Incomplete(_))
+        ));
+    }
+}
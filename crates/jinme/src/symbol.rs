@@ -13,9 +13,13 @@
 /// assert_eq!(sym.name(), "my-var");
 /// ```
 use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 pub mod optics;
 
+/// The counter behind [`Symbol::gensym`], shared by all generated symbols.
+static NEXT_GENSYM_ID: AtomicU64 = AtomicU64::new(1);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Symbol {
     /// An unqualified symbol (just a name)
@@ -35,6 +39,21 @@ impl Symbol {
         Self::Unqualified(SymbolUnqualified(name.to_owned()))
     }
 
+    /// Create a new unqualified symbol named `prefix` followed by a number from a global
+    /// counter, so that generated symbols never collide with each other.
+    ///
+    /// ```
+    /// # use jinme::prelude::*;
+    /// let a = Symbol::gensym("G__");
+    /// let b = Symbol::gensym("G__");
+    /// assert!(a.name().starts_with("G__"));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn gensym(prefix: &str) -> Self {
+        let id = NEXT_GENSYM_ID.fetch_add(1, Ordering::Relaxed);
+        Self::new_unqualified(&format!("{prefix}{id}"))
+    }
+
     pub fn new_qualified(namespace: &str, name: &str) -> Self {
         Self::Qualified(SymbolQualified(namespace.to_owned(), name.to_owned()))
     }
@@ -0,0 +1,268 @@
+use crate::prelude::*;
+use std::{fmt, sync::LazyLock};
+
+/// A value written as a tag followed by a form, `#tag form`.
+///
+/// Values of types jinme has no variant for are tagged literals: `#inst` timestamps,
+/// `#uuid` UUIDs, and forms with tags that have no reader, when the reader is asked to keep
+/// them (see [`read2::UnknownTags`](crate::read2::UnknownTags)). Tagged literals are equal
+/// when their tags and forms are, so the built-in readers normalize their forms.
+///
+/// # Example
+///
+/// ```
+/// # use jinme::prelude::*;
+/// let inst = tagged_literal::read_inst(&Value::string("2020-01-01T01:00:00+01:00".to_owned())).unwrap();
+/// assert_eq!(inst.to_string(), "#inst \"2020-01-01T00:00:00.000-00:00\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaggedLiteral {
+    tag: Symbol,
+    form: PtrValue,
+}
+
+impl TaggedLiteral {
+    pub fn new(tag: Symbol, form: PtrValue) -> Self {
+        Self { tag, form }
+    }
+
+    pub fn tag(&self) -> &Symbol {
+        &self.tag
+    }
+
+    pub fn form(&self) -> &PtrValue {
+        &self.form
+    }
+
+    /// Returns `true` if this is an `#inst` timestamp
+    pub fn is_inst(&self) -> bool {
+        self.tag == Symbol::new_unqualified("inst")
+    }
+
+    /// Returns `true` if this is a `#uuid`
+    pub fn is_uuid(&self) -> bool {
+        self.tag == Symbol::new_unqualified("uuid")
+    }
+}
+
+impl fmt::Display for TaggedLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} {}", self.tag, self.form)
+    }
+}
+
+/// `yyyy-mm-ddThh:mm:ss.fff+hh:mm`, where any trailing part of the date and time may be left
+/// out, as may the offset, which is then UTC
+static TIMESTAMP: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r"^(\d{4})(?:-(\d{2})(?:-(\d{2})(?:[Tt](\d{2})(?::(\d{2})(?::(\d{2})(?:\.(\d+))?)?)?)?)?)?(?:[Zz]|([+-])(\d{2}):(\d{2}))?$",
+    )
+    .expect("timestamp regex compiles")
+});
+
+static UUID: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+    )
+    .expect("uuid regex compiles")
+});
+
+/// The reader for `#inst "timestamp"`: the instant an RFC 3339 timestamp names, as an
+/// `#inst` of the same instant in UTC, with millisecond precision unless the timestamp has
+/// more. As in Clojure, trailing parts of the timestamp may be left out, e.g. `#inst "2020"`.
+pub fn read_inst(form: &Value) -> Result<TaggedLiteral, String> {
+    let Some(text) = form.preview_string_ref() else {
+        return Err(format!("#inst requires a string, but got: {form}"));
+    };
+    let invalid = || format!("invalid timestamp: {text}");
+    let captures = TIMESTAMP.captures(text).ok_or_else(invalid)?;
+    let part = |i: usize, default: i64| {
+        captures
+            .get(i)
+            .map_or(default, |part| part.as_str().parse().unwrap_or(default))
+    };
+    let (year, month, day) = (part(1, 0), part(2, 1), part(3, 1));
+    let (hour, minute, second) = (part(4, 0), part(5, 0), part(6, 0));
+    // the fraction of a second, to nanoseconds, e.g. `.5` is 500_000_000
+    let nanos = captures.get(7).map_or(0, |fraction| {
+        let digits = &fraction.as_str()[..fraction.len().min(9)];
+        digits.parse::<i64>().unwrap_or(0) * 10_i64.pow(9 - digits.len() as u32)
+    });
+    let offset_sign = match captures.get(8).map(|sign| sign.as_str()) {
+        Some("-") => -1,
+        _ => 1,
+    };
+    let (offset_hour, offset_minute) = (part(9, 0), part(10, 0));
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+        || offset_hour > 23
+        || offset_minute > 59
+    {
+        return Err(invalid());
+    }
+
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second
+        - offset_sign * (offset_hour * 3_600 + offset_minute * 60);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    if !(0..=9999).contains(&year) {
+        return Err(format!("timestamp out of range: {text}"));
+    }
+    let time = seconds.rem_euclid(86_400);
+    let fraction = if nanos % 1_000_000 == 0 {
+        format!("{:03}", nanos / 1_000_000)
+    } else {
+        format!("{:09}", nanos)
+    };
+    let normalized = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{}-00:00",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60,
+        fraction
+    );
+    Ok(TaggedLiteral::new(
+        Symbol::new_unqualified("inst"),
+        Value::string_ptr(normalized),
+    ))
+}
+
+/// The reader for `#uuid "uuid"`: a `#uuid` of the UUID in its canonical lowercase form
+pub fn read_uuid(form: &Value) -> Result<TaggedLiteral, String> {
+    let Some(text) = form.preview_string_ref() else {
+        return Err(format!("#uuid requires a string, but got: {form}"));
+    };
+    if !UUID.is_match(text) {
+        return Err(format!("invalid uuid: {text}"));
+    }
+    Ok(TaggedLiteral::new(
+        Symbol::new_unqualified("uuid"),
+        Value::string_ptr(text.to_ascii_lowercase()),
+    ))
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days from 1970-01-01 to the given date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // counting years from March, so that the leap day is the last day of the year
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date `days` from 1970-01-01, the inverse of [`days_from_civil`]
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inst(text: &str) -> Result<String, String> {
+        read_inst(&Value::string(text.to_owned())).map(|inst| inst.form().to_string())
+    }
+
+    #[test]
+    fn civil_days_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        for days in [-719_468, -1, 0, 59, 11_016, 11_017, 2_932_896] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn inst_is_normalized_to_utc() {
+        assert_eq!(
+            inst("2020-01-01T00:00:00Z"),
+            Ok("\"2020-01-01T00:00:00.000-00:00\"".to_owned())
+        );
+        assert_eq!(
+            inst("2020-03-01T00:30:00+01:00"),
+            Ok("\"2020-02-29T23:30:00.000-00:00\"".to_owned())
+        );
+        assert_eq!(
+            inst("2019-12-31T23:00:00.5-01:30"),
+            Ok("\"2020-01-01T00:30:00.500-00:00\"".to_owned())
+        );
+        assert_eq!(
+            inst("2020-01-01T00:00:00.123456Z"),
+            Ok("\"2020-01-01T00:00:00.123456000-00:00\"".to_owned())
+        );
+        assert_eq!(
+            inst("2020"),
+            Ok("\"2020-01-01T00:00:00.000-00:00\"".to_owned())
+        );
+        assert_eq!(
+            inst("2020-06"),
+            Ok("\"2020-06-01T00:00:00.000-00:00\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn invalid_inst() {
+        for text in [
+            "2020-13-01",
+            "2019-02-29",
+            "2020-01-01T24:00:00Z",
+            "20200101",
+            "2020-01-01T00:00:00+1",
+        ] {
+            assert_eq!(inst(text), Err(format!("invalid timestamp: {text}")));
+        }
+        assert_eq!(
+            inst("0000-01-01T00:00:00+01:00"),
+            Err("timestamp out of range: 0000-01-01T00:00:00+01:00".to_owned())
+        );
+        assert!(read_inst(&Value::integer(1)).is_err());
+    }
+
+    #[test]
+    fn uuid_is_lowercased() {
+        let uuid = read_uuid(&Value::string(
+            "F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6".to_owned(),
+        ))
+        .unwrap();
+        assert_eq!(
+            uuid.to_string(),
+            "#uuid \"f81d4fae-7dec-11d0-a765-00a0c91e6bf6\""
+        );
+        assert!(read_uuid(&Value::string("f81d4fae-7dec-11d0-a765".to_owned())).is_err());
+    }
+}
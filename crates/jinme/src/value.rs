@@ -1,5 +1,5 @@
 use crate::{optics::{Preview, View}, prelude::*};
-use ::std::{fmt, sync::Arc};
+use ::std::{cmp::Ordering, fmt, hash::{Hash, Hasher}, sync::Arc};
 
 mod from;
 pub mod optics;
@@ -24,12 +24,14 @@ pub type PtrValue = Arc<Value>;
 /// - `Integer`: 64-bit signed integers
 /// - `Float`: Floating-point numbers (see [`Float`](crate::float::Float))
 /// - `String`: UTF-8 strings
+/// - `Char`: Unicode characters
 /// - `Symbol`: Symbols for namespaced identifiers (see [`Symbol`](crate::symbol::Symbol))
 /// - `Keyword`: Keywords for metadata and attributes (see [`Keyword`](crate::keyword::Keyword))
 /// - `List`: Linked lists (see [`List`](crate::list::List))
 /// - `Vector`: Vectors (see [`Vector`](crate::vector::Vector))
 /// - `Set`: Sets (see [`Set`](crate::set::Set))
 /// - `Map`: Maps (see [`Map`](crate::map::Map))
+/// - `Tagged`: Tagged literals such as `#inst` (see [`TaggedLiteral`](crate::tagged_literal::TaggedLiteral))
 /// - `Var`: References to namespace-bound variables (see [`Var`](crate::var::Var))
 /// - `Function`: Functions (see [`Function`](crate::function::Function))
 /// - `Handle`: Handles for external resources (see [`Handle`](crate::handle::Handle))
@@ -57,7 +59,7 @@ pub type PtrValue = Arc<Value>;
 /// assert!(val.is_integer());
 /// assert!(!val.is_string());
 /// ```
-#[derive(Hash, Ord, PartialOrd, Eq, Clone)]
+#[derive(Eq, Clone)]
 pub enum Value {
     /// The empty value (`nil` in Clojure)
     Nil(Option<Arc<Map>>),
@@ -69,6 +71,8 @@ pub enum Value {
     Float(Float, Option<Arc<Map>>),
     /// UTF-8 strings
     String(String, Option<Arc<Map>>),
+    /// Unicode characters
+    Char(char, Option<Arc<Map>>),
     /// Symbols for namespaced identifiers
     Symbol(Symbol, Option<Arc<Map>>),
     /// Keywords for metadata and attributes
@@ -81,6 +85,8 @@ pub enum Value {
     Set(Set, Option<Arc<Map>>),
     /// Maps
     Map(Map, Option<Arc<Map>>),
+    /// Tagged literals, `#tag form`
+    Tagged(TaggedLiteral, Option<Arc<Map>>),
     /// References to namespace-bound variables
     Var(PtrVar, Option<Arc<Map>>),
     /// Functions
@@ -94,6 +100,16 @@ impl Value {
     pub fn preview<T>(&self) -> Option<T> where Self: Preview<T>, { <Self as Preview<T>>::preview(self) }
 }
 
+/// The characters written by name, e.g. `\newline`, when read or printed
+pub(crate) const CHAR_NAMES: [(&str, char); 6] = [
+    ("newline", '\n'),
+    ("space", ' '),
+    ("tab", '\t'),
+    ("backspace", '\u{8}'),
+    ("formfeed", '\u{c}'),
+    ("return", '\r'),
+];
+
 impl Value {
     pub fn view_integer(&self) -> i64 { match self { Self::Integer(integer, _) => integer.to_owned(), _ => panic!("Expected integer value, found {}", self), } }
     pub fn preview_integer(&self) -> Option<i64> { optics::preview_integer(self) }
@@ -103,6 +119,9 @@ impl Value {
     pub fn preview_string(&self) -> Option<String> { optics::preview_string(self) }
     pub fn preview_string_ref(&self) -> Option<&str> { optics::preview_string_ref(self) }
 
+    pub fn view_char(&self) -> char { match self { Self::Char(c, _) => *c, _ => panic!("Expected char value, found {}", self), } }
+    pub fn preview_char(&self) -> Option<char> { optics::preview_char(self) }
+
     pub fn view_list(&self) -> List { match self { Self::List(list, _) => list.to_owned(), _ => panic!("Expected list value, found {}", self), } }
     pub fn view_list_ref(&self) -> &List { match self { Self::List(list, _) => list, _ => panic!("Expected list value, found {}", self), } }
     pub fn preview_list(&self) -> Option<List> { optics::preview_list(self) }
@@ -132,12 +151,14 @@ impl PartialEq for Value {
             (Self::Integer(lhs, _), Self::Integer(rhs, _)) => lhs == rhs,
             (Self::Float(lhs, _), Self::Float(rhs, _)) => lhs == rhs,
             (Self::String(lhs, _), Self::String(rhs, _)) => lhs == rhs,
+            (Self::Char(lhs, _), Self::Char(rhs, _)) => lhs == rhs,
             (Self::Symbol(lhs, _), Self::Symbol(rhs, _)) => lhs == rhs,
             (Self::Keyword(lhs, _), Self::Keyword(rhs, _)) => lhs == rhs,
             (Self::List(lhs, _), Self::List(rhs, _)) => lhs == rhs,
             (Self::Vector(lhs, _), Self::Vector(rhs, _)) => lhs == rhs,
             (Self::Set(lhs, _), Self::Set(rhs, _)) => lhs == rhs,
             (Self::Map(lhs, _), Self::Map(rhs, _)) => lhs == rhs,
+            (Self::Tagged(lhs, _), Self::Tagged(rhs, _)) => lhs == rhs,
             (Self::Var(lhs, _), Self::Var(rhs, _)) => lhs == rhs,
             (Self::Function(lhs, _), Self::Function(rhs, _)) => lhs == rhs,
             (Self::Handle(lhs, _), Self::Handle(rhs, _)) => lhs == rhs,
@@ -146,6 +167,87 @@ impl PartialEq for Value {
     }
 }
 
+// Metadata takes no part in equality, so it takes no part in hashing or ordering either;
+// otherwise a symbol carrying reader positions would miss as a map key or set member.
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.variant_index().hash(state);
+        match self {
+            Self::Nil(_) => {}
+            Self::Boolean(value, _) => value.hash(state),
+            Self::Integer(value, _) => value.hash(state),
+            Self::Float(value, _) => value.hash(state),
+            Self::String(value, _) => value.hash(state),
+            Self::Char(value, _) => value.hash(state),
+            Self::Symbol(value, _) => value.hash(state),
+            Self::Keyword(value, _) => value.hash(state),
+            Self::List(value, _) => value.hash(state),
+            Self::Vector(value, _) => value.hash(state),
+            Self::Set(value, _) => value.hash(state),
+            Self::Map(value, _) => value.hash(state),
+            Self::Tagged(value, _) => value.hash(state),
+            Self::Var(value, _) => value.hash(state),
+            Self::Function(value, _) => value.hash(state),
+            Self::Handle(value, _) => value.hash(state),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Nil(_), Self::Nil(_)) => Ordering::Equal,
+            (Self::Boolean(lhs, _), Self::Boolean(rhs, _)) => lhs.cmp(rhs),
+            (Self::Integer(lhs, _), Self::Integer(rhs, _)) => lhs.cmp(rhs),
+            (Self::Float(lhs, _), Self::Float(rhs, _)) => lhs.cmp(rhs),
+            (Self::String(lhs, _), Self::String(rhs, _)) => lhs.cmp(rhs),
+            (Self::Char(lhs, _), Self::Char(rhs, _)) => lhs.cmp(rhs),
+            (Self::Symbol(lhs, _), Self::Symbol(rhs, _)) => lhs.cmp(rhs),
+            (Self::Keyword(lhs, _), Self::Keyword(rhs, _)) => lhs.cmp(rhs),
+            (Self::List(lhs, _), Self::List(rhs, _)) => lhs.cmp(rhs),
+            (Self::Vector(lhs, _), Self::Vector(rhs, _)) => lhs.cmp(rhs),
+            (Self::Set(lhs, _), Self::Set(rhs, _)) => lhs.cmp(rhs),
+            (Self::Map(lhs, _), Self::Map(rhs, _)) => lhs.cmp(rhs),
+            (Self::Tagged(lhs, _), Self::Tagged(rhs, _)) => lhs.cmp(rhs),
+            (Self::Var(lhs, _), Self::Var(rhs, _)) => lhs.cmp(rhs),
+            (Self::Function(lhs, _), Self::Function(rhs, _)) => lhs.cmp(rhs),
+            (Self::Handle(lhs, _), Self::Handle(rhs, _)) => lhs.cmp(rhs),
+            _ => self.variant_index().cmp(&other.variant_index()),
+        }
+    }
+}
+
+impl Value {
+    /// Position of this value's variant in declaration order, used to hash and order
+    /// values of different kinds.
+    fn variant_index(&self) -> u8 {
+        match self {
+            Self::Nil(_) => 0,
+            Self::Boolean(..) => 1,
+            Self::Integer(..) => 2,
+            Self::Float(..) => 3,
+            Self::String(..) => 4,
+            Self::Char(..) => 5,
+            Self::Symbol(..) => 6,
+            Self::Keyword(..) => 7,
+            Self::List(..) => 8,
+            Self::Vector(..) => 9,
+            Self::Set(..) => 10,
+            Self::Map(..) => 11,
+            Self::Tagged(..) => 12,
+            Self::Var(..) => 13,
+            Self::Function(..) => 14,
+            Self::Handle(..) => 15,
+        }
+    }
+}
+
 impl Value {
     /// Returns `true` if this value is `nil`
     #[inline]
@@ -177,6 +279,12 @@ impl Value {
         matches!(self, Self::String(..))
     }
 
+    /// Returns `true` if this value is a character
+    #[inline]
+    pub fn is_char(&self) -> bool {
+        matches!(self, Self::Char(..))
+    }
+
     /// Returns `true` if this value is a symbol
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -213,6 +321,12 @@ impl Value {
         matches!(self, Self::Map(..))
     }
 
+    /// Returns `true` if this value is a tagged literal
+    #[inline]
+    pub fn is_tagged(&self) -> bool {
+        matches!(self, Self::Tagged(..))
+    }
+
     /// Returns `true` if this value is a Var reference
     #[inline]
     pub fn is_var(&self) -> bool {
@@ -231,6 +345,12 @@ impl Value {
         matches!(self, Self::Handle(..))
     }
 
+    /// Returns `true` if this value is logically true, i.e. neither `nil` nor `false`
+    #[inline]
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Self::Nil(..) | Self::Boolean(false, ..))
+    }
+
     /// Creates a `nil` value
     #[inline]
     pub fn nil() -> Self {
@@ -261,6 +381,12 @@ impl Value {
         Self::String(string, None)
     }
 
+    /// Creates a character value
+    #[inline]
+    pub fn char(c: char) -> Self {
+        Self::Char(c, None)
+    }
+
     /// Creates a symbol value from an unqualified symbol name
     #[inline]
     pub fn symbol(symbol: Symbol) -> Self {
@@ -329,6 +455,9 @@ impl Value {
     pub fn map_from(pairs: Vec<(PtrValue, PtrValue)>) -> Self {
         Self::Map(Map::new(pairs), None)
     }
+    pub fn tagged(tagged: TaggedLiteral) -> Self {
+        Self::Tagged(tagged, None)
+    }
     pub fn var(var: PtrVar) -> Self {
         Self::Var(var.clone(), var.meta())
     }
@@ -356,6 +485,9 @@ impl Value {
     pub fn string_ptr(string: String) -> PtrValue {
         Arc::new(Self::String(string, None))
     }
+    pub fn char_ptr(c: char) -> PtrValue {
+        Arc::new(Self::Char(c, None))
+    }
     pub fn symbol_ptr(symbol: Symbol) -> PtrValue {
         Arc::new(Self::Symbol(symbol, None))
     }
@@ -386,6 +518,9 @@ impl Value {
     pub fn map_ptr(map: Map) -> PtrValue {
         Arc::new(Self::Map(map, None))
     }
+    pub fn tagged_ptr(tagged: TaggedLiteral) -> PtrValue {
+        Arc::new(Self::Tagged(tagged, None))
+    }
     pub fn var_ptr(var: PtrVar) -> PtrValue {
         Arc::new(Self::Var(var.clone(), var.meta()))
     }
@@ -412,12 +547,14 @@ impl Value {
             Value::Integer(integer, _) => Value::Integer(integer.to_owned(), meta),
             Value::Float(float, _) => Value::Float(float.to_owned(), meta),
             Value::String(string, _) => Value::String(string.to_owned(), meta),
+            Value::Char(c, _) => Value::Char(c.to_owned(), meta),
             Value::Symbol(symbol, _) => Value::Symbol(symbol.to_owned(), meta),
             Value::Keyword(keyword, _) => Value::Keyword(keyword.to_owned(), meta),
             Value::List(list, _) => Value::List(list.to_owned(), meta),
             Value::Vector(vector, _) => Value::Vector(vector.to_owned(), meta),
             Value::Set(set, _) => Value::Set(set.to_owned(), meta),
             Value::Map(map, _) => Value::Map(map.to_owned(), meta),
+            Value::Tagged(tagged, _) => Value::Tagged(tagged.to_owned(), meta),
             Value::Var(var, _) => Value::Var(var.to_owned(), meta),
             Value::Function(function, _) => Value::Function(function.to_owned(), meta),
             Value::Handle(handle, _) => Value::Handle(handle.to_owned(), meta),
@@ -560,12 +697,14 @@ impl fmt::Debug for Value {
             Self::Integer(integer, _meta) => write!(f, "Value::Integer({})", integer),
             Self::Float(float, _meta) => write!(f, "Value::Float({:?})", float),
             Self::String(string, _meta) => write!(f, "Value::String({:?})", string),
+            Self::Char(c, _meta) => write!(f, "Value::Char({:?})", c),
             Self::Symbol(symbol, _meta) => write!(f, "Value::Symbol({:?})", symbol),
             Self::Keyword(keyword, _meta) => write!(f, "Value::Keyword({:?})", keyword),
             Self::List(list, _meta) => write!(f, "Value::List({:?})", list),
             Self::Vector(vector, _meta) => write!(f, "Value::Vector({:?})", vector),
             Self::Set(set, _meta) => write!(f, "Value::Set({:?})", set),
             Self::Map(map, _meta) => write!(f, "Value::Map({:?})", map),
+            Self::Tagged(tagged, _meta) => write!(f, "Value::Tagged({:?})", tagged),
             Self::Var(var, _meta) => {
                 write!(f, "Value::Var({:p})", PtrVar::as_ptr(var).cast::<()>())
             }
@@ -582,13 +721,19 @@ impl fmt::Display for Value {
             Self::Boolean(boolean, _meta) => write!(f, "{}", boolean),
             Self::Integer(integer, _meta) => write!(f, "{}", integer),
             Self::Float(float, _meta) => write!(f, "{}", float),
-            Self::String(string, _meta) => write!(f, "\"{}\"", string),
+            Self::String(string, _meta) => write_escaped_string(f, string),
+            Self::Char(c, _meta) => match CHAR_NAMES.iter().find(|(_, named)| named == c) {
+                Some((name, _)) => write!(f, "\\{}", name),
+                None if c.is_control() => write!(f, "\\u{:04x}", *c as u32),
+                None => write!(f, "\\{}", c),
+            },
             Self::Symbol(symbol, _meta) => write!(f, "{}", symbol),
             Self::Keyword(keyword, _meta) => write!(f, "{}", keyword),
             Self::List(list, _meta) => write!(f, "{}", list),
             Self::Vector(vector, _meta) => write!(f, "{}", vector),
             Self::Set(set, _meta) => write!(f, "{}", set),
             Self::Map(map, _meta) => write!(f, "{}", map),
+            Self::Tagged(tagged, _meta) => write!(f, "{}", tagged),
             Self::Var(var, _meta) => write!(f, "#var[{:p}]", PtrVar::as_ptr(var).cast::<()>()),
             Self::Function(func, _meta) => write!(
                 f,
@@ -610,6 +755,25 @@ impl fmt::Display for Value {
     }
 }
 
+/// Writes `string` quoted, escaping what the reader would otherwise not read back as itself
+fn write_escaped_string(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in string.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\t' => write!(f, "\\t")?,
+            '\r' => write!(f, "\\r")?,
+            '\u{8}' => write!(f, "\\b")?,
+            '\u{c}' => write!(f, "\\f")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
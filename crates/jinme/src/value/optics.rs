@@ -304,6 +304,62 @@ pub fn try_modify_string(
     prism_string().try_modify(value, f)
 }
 
+// char
+// ========================================
+
+/// Returns a prism for the `Value::Char` variant.
+pub fn prism_char() -> impl Prism<Value, char> {
+    PrismImpl::new(
+        |v| {
+            if let Value::Char(c, _) = v {
+                Some(*c)
+            } else {
+                None
+            }
+        },
+        Value::char_ptr,
+    )
+}
+
+/// Previews whether a `Value` is a character and extracts the char value.
+/// Returns `Some(char)` if the value is a character, `None` otherwise.
+pub fn preview_char(value: &Value) -> Option<char> {
+    prism_char().preview(value)
+}
+
+/// Previews the char value within a `Value` by reference.
+/// Returns `Some(&char)` if the value is a character, `None` otherwise.
+pub fn preview_char_ref(value: &Value) -> Option<&char> {
+    if let Value::Char(c, _) = value {
+        Some(c)
+    } else {
+        None
+    }
+}
+
+/// Constructs a character `Value` from a char.
+pub fn review_char(c: char) -> PtrValue {
+    prism_char().review(c)
+}
+
+/// Applies a function to the inner char of a `Value`, if it is a character.
+/// Returns the modified `Value` if this is a character, otherwise returns the original unchanged.
+pub fn modify_char(value: PtrValue, f: impl Fn(char) -> char) -> PtrValue {
+    prism_char().modify(value, f)
+}
+
+/// Sets the char value of a `Value`, if it is a character.
+/// Returns a new `Value` with the updated char if this is a character, otherwise returns the original unchanged.
+pub fn set_char(value: PtrValue, c: char) -> PtrValue {
+    prism_char().set(value, c)
+}
+
+/// Attempts to apply a function to the inner char of a `Value`.
+/// Returns `Ok(modified_value)` if this is a character, `Err(original_value)` otherwise.
+pub fn try_modify_char(value: PtrValue, f: impl Fn(char) -> char) -> Result<PtrValue, PtrValue> {
+    prism_char().try_modify(value, f)
+}
+
 // symbol
 // ========================================
 
@@ -759,6 +815,66 @@ pub fn try_modify_map(value: PtrValue, f: impl Fn(Map) -> Map) -> Result<PtrValu
     prism_map().try_modify(value, f)
 }
 
+// tagged
+// ========================================
+
+/// Returns a prism for the `Value::Tagged` variant.
+pub fn prism_tagged() -> impl Prism<Value, TaggedLiteral> {
+    PrismImpl::new(
+        |v| {
+            if let Value::Tagged(tagged, _) = v {
+                Some(tagged.clone())
+            } else {
+                None
+            }
+        },
+        Value::tagged_ptr,
+    )
+}
+
+/// Previews whether a `Value` is a tagged literal and extracts the TaggedLiteral value.
+/// Returns `Some(TaggedLiteral)` if the value is a tagged literal, `None` otherwise.
+pub fn preview_tagged(value: &Value) -> Option<TaggedLiteral> {
+    prism_tagged().preview(value)
+}
+
+/// Previews the `TaggedLiteral` value within a `Value` by reference.
+/// Returns `Some(&TaggedLiteral)` if the value is a tagged literal, `None` otherwise.
+/// This avoids cloning the `TaggedLiteral` value.
+pub fn preview_tagged_ref(value: &Value) -> Option<&TaggedLiteral> {
+    if let Value::Tagged(tagged, _) = value {
+        Some(tagged)
+    } else {
+        None
+    }
+}
+
+/// Constructs a tagged literal `Value` from a TaggedLiteral.
+pub fn review_tagged(tagged: TaggedLiteral) -> PtrValue {
+    prism_tagged().review(tagged)
+}
+
+/// Applies a function to the inner TaggedLiteral of a `Value`, if it is a tagged literal.
+/// Returns the modified `Value` if this is a tagged literal, otherwise returns the original unchanged.
+pub fn modify_tagged(value: PtrValue, f: impl Fn(TaggedLiteral) -> TaggedLiteral) -> PtrValue {
+    prism_tagged().modify(value, f)
+}
+
+/// Sets the TaggedLiteral value of a `Value`, if it is a tagged literal.
+/// Returns a new `Value` with the updated TaggedLiteral if this is a tagged literal, otherwise returns the original unchanged.
+pub fn set_tagged(value: PtrValue, tagged: TaggedLiteral) -> PtrValue {
+    prism_tagged().set(value, tagged)
+}
+
+/// Attempts to apply a function to the inner TaggedLiteral of a `Value`.
+/// Returns `Ok(modified_value)` if this is a tagged literal, `Err(original_value)` otherwise.
+pub fn try_modify_tagged(
+    value: PtrValue,
+    f: impl Fn(TaggedLiteral) -> TaggedLiteral,
+) -> Result<PtrValue, PtrValue> {
+    prism_tagged().try_modify(value, f)
+}
+
 // var
 // ========================================
 
@@ -949,12 +1065,14 @@ pub fn preview_meta(value: &Value) -> Option<Arc<Map>> {
         Value::Integer(_, meta) => meta.clone(),
         Value::Float(_, meta) => meta.clone(),
         Value::String(_, meta) => meta.clone(),
+        Value::Char(_, meta) => meta.clone(),
         Value::Symbol(_, meta) => meta.clone(),
         Value::Keyword(_, meta) => meta.clone(),
         Value::List(_, meta) => meta.clone(),
         Value::Vector(_, meta) => meta.clone(),
         Value::Set(_, meta) => meta.clone(),
         Value::Map(_, meta) => meta.clone(),
+        Value::Tagged(_, meta) => meta.clone(),
         Value::Var(_, meta) => meta.clone(),
         Value::Function(_, meta) => meta.clone(),
         Value::Handle(_, meta) => meta.clone(),
@@ -974,12 +1092,14 @@ pub fn preview_meta_ref(value: &Value) -> Option<&Arc<Map>> {
         Value::Integer(_, meta) => meta.as_ref(),
         Value::Float(_, meta) => meta.as_ref(),
         Value::String(_, meta) => meta.as_ref(),
+        Value::Char(_, meta) => meta.as_ref(),
         Value::Symbol(_, meta) => meta.as_ref(),
         Value::Keyword(_, meta) => meta.as_ref(),
         Value::List(_, meta) => meta.as_ref(),
         Value::Vector(_, meta) => meta.as_ref(),
         Value::Set(_, meta) => meta.as_ref(),
         Value::Map(_, meta) => meta.as_ref(),
+        Value::Tagged(_, meta) => meta.as_ref(),
         Value::Var(_, meta) => meta.as_ref(),
         Value::Function(_, meta) => meta.as_ref(),
         Value::Handle(_, meta) => meta.as_ref(),
@@ -995,12 +1115,14 @@ pub fn modify_meta(value: PtrValue, f: impl Fn(Option<Arc<Map>>) -> Option<Arc<M
         Value::Integer(i, meta) => Arc::new(Value::Integer(i, f(meta))),
         Value::Float(fl, meta) => Arc::new(Value::Float(fl, f(meta))),
         Value::String(s, meta) => Arc::new(Value::String(s, f(meta))),
+        Value::Char(c, meta) => Arc::new(Value::Char(c, f(meta))),
         Value::Symbol(sym, meta) => Arc::new(Value::Symbol(sym, f(meta))),
         Value::Keyword(kw, meta) => Arc::new(Value::Keyword(kw, f(meta))),
         Value::List(list, meta) => Arc::new(Value::List(list, f(meta))),
         Value::Vector(vec, meta) => Arc::new(Value::Vector(vec, f(meta))),
         Value::Set(set, meta) => Arc::new(Value::Set(set, f(meta))),
         Value::Map(map, meta) => Arc::new(Value::Map(map, f(meta))),
+        Value::Tagged(tagged, meta) => Arc::new(Value::Tagged(tagged, f(meta))),
         Value::Var(var, meta) => Arc::new(Value::Var(var, f(meta))),
         Value::Function(func, meta) => Arc::new(Value::Function(func, f(meta))),
         Value::Handle(handle, meta) => Arc::new(Value::Handle(handle, f(meta))),
@@ -23,6 +23,7 @@ use crate::list::List;
 use crate::map::Map;
 use crate::set::Set;
 use crate::symbol::Symbol;
+use crate::tagged_literal::TaggedLiteral;
 use crate::value::PtrValue;
 use crate::value::optics;
 use crate::var::PtrVar;
@@ -53,6 +54,12 @@ pub fn modify_string(f: impl Fn(String) -> String + Clone) -> impl Fn(PtrValue)
     move |value| optics::modify_string(value, f.clone())
 }
 
+/// Creates a partialed modifier for character values.
+/// Returns a function that takes a `Value` and modifies it if it is a character.
+pub fn modify_char(f: impl Fn(char) -> char + Clone) -> impl Fn(PtrValue) -> PtrValue {
+    move |value| optics::modify_char(value, f.clone())
+}
+
 /// Creates a partialed modifier for symbol values.
 /// Returns a function that takes a `Value` and modifies it if it is a symbol.
 pub fn modify_symbol(f: impl Fn(Symbol) -> Symbol + Clone) -> impl Fn(PtrValue) -> PtrValue {
@@ -89,6 +96,14 @@ pub fn modify_map(f: impl Fn(Map) -> Map + Clone) -> impl Fn(PtrValue) -> PtrVal
     move |value| optics::modify_map(value, f.clone())
 }
 
+/// Creates a partialed modifier for tagged literal values.
+/// Returns a function that takes a `Value` and modifies it if it is a tagged literal.
+pub fn modify_tagged(
+    f: impl Fn(TaggedLiteral) -> TaggedLiteral + Clone,
+) -> impl Fn(PtrValue) -> PtrValue {
+    move |value| optics::modify_tagged(value, f.clone())
+}
+
 /// Creates a partialed modifier for var values.
 /// Returns a function that takes a `Value` and modifies it if it is a var.
 pub fn modify_var(f: impl Fn(PtrVar) -> PtrVar + Clone) -> impl Fn(PtrValue) -> PtrValue {
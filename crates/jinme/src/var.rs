@@ -2,6 +2,7 @@ use crate::prelude::*;
 use ::std::collections::HashSet;
 use ::std::sync::Arc;
 use ::std::{
+    cell::RefCell,
     hash::{Hash, Hasher},
     sync::Mutex,
 };
@@ -20,6 +21,13 @@ thread_local! {
     /// - Recursive functions accessing their own bindings
     /// - Let bindings where the RHS eval accesses the variable being bound
     static ACQUIRING_VARS: Mutex<HashSet<usize>> = Mutex::new(HashSet::new());
+
+    /// Thread-local binding frames pushed by [`Var::push_thread_bindings`], innermost last.
+    ///
+    /// [`Var::deref`] consults these frames (innermost first) before the root value, which is
+    /// what lets `binding` redirect a dynamic Var such as `*out*` for a block of code on this
+    /// thread only. Frames hold their Vars so the pointer identities stay valid.
+    static BINDING_FRAMES: RefCell<Vec<Vec<(PtrVar, PtrValue)>>> = const { RefCell::new(Vec::new()) };
 }
 
 /// A mutable (possibly empty) [`Value`]-holding place that supports re-entrant access.
@@ -112,24 +120,130 @@ impl Var {
 
     /// Check if the variable is unbound
     pub fn is_unbound(&self) -> bool {
-        self.value
-            .lock()
-            .expect("mutex poisoned while checking if Var is unbound")
-            .is_none()
+        !self.is_bound()
     }
 
-    /// Check if the variable is bound
+    /// Check if the variable is bound, either at its root or on this thread
     pub fn is_bound(&self) -> bool {
-        self.value
-            .lock()
-            .expect("mutex poisoned while checking if Var is bound")
-            .is_some()
+        self.thread_binding().is_some()
+            || self
+                .value
+                .lock()
+                .expect("mutex poisoned while checking if Var is bound")
+                .is_some()
+    }
+
+    /// Check if the variable is dynamic, i.e. its metadata has a truthy `:dynamic`.
+    ///
+    /// Only dynamic Vars may be given thread-local bindings.
+    pub fn is_dynamic(&self) -> bool {
+        self.get_meta(&Value::keyword_unqualified_ptr("dynamic"))
+            .is_some_and(|dynamic| dynamic.is_truthy())
+    }
+
+    /// Check if the variable is a macro, i.e. its metadata has a truthy `:macro`.
+    ///
+    /// Calls to macros are expanded by the evaluator before evaluation.
+    pub fn is_macro(&self) -> bool {
+        self.get_meta(&Value::keyword_unqualified_ptr("macro"))
+            .is_some_and(|is_macro| is_macro.is_truthy())
+    }
+
+    /// Check if the variable is a macro whose expander takes the implicit `&form` and `&env`
+    /// arguments before the macro call's arguments, i.e. its metadata has a truthy
+    /// `:jinme/form-env`. Macros defined with `defmacro` do; macros bound from Rust don't.
+    pub fn takes_form_env(&self) -> bool {
+        self.get_meta(&Value::keyword_qualified_ptr("jinme", "form-env"))
+            .is_some_and(|form_env| form_env.is_truthy())
+    }
+
+    /// Push a frame of thread-local bindings, shadowing the Vars' current values on this
+    /// thread until the matching [`Var::pop_thread_bindings`].
+    ///
+    /// Panics if any of the Vars is not dynamic.
+    pub fn push_thread_bindings(bindings: Vec<(PtrVar, PtrValue)>) {
+        for (var, _) in &bindings {
+            if !var.is_dynamic() {
+                panic!("cannot dynamically bind non-dynamic Var");
+            }
+        }
+        BINDING_FRAMES.with(|frames| frames.borrow_mut().push(bindings));
+    }
+
+    /// Pop the innermost frame pushed by [`Var::push_thread_bindings`] on this thread.
+    ///
+    /// Panics if there is no frame to pop.
+    pub fn pop_thread_bindings() {
+        BINDING_FRAMES.with(|frames| {
+            frames
+                .borrow_mut()
+                .pop()
+                .expect("pop_thread_bindings called without a matching push_thread_bindings");
+        });
+    }
+
+    /// Run `f` with a frame of thread-local bindings pushed, popping it afterwards even if `f`
+    /// panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use jinme::prelude::*;
+    /// # use std::sync::Arc;
+    /// let dynamic = Arc::new(Map::new(vec![(
+    ///     Value::keyword_unqualified_ptr("dynamic"),
+    ///     Value::boolean_ptr(true),
+    /// )]));
+    /// let var = Arc::new(Var::new_bound_with_meta(Value::integer_ptr(1), Some(dynamic)));
+    /// let inner = Var::with_thread_bindings(vec![(var.clone(), Value::integer_ptr(2))], || {
+    ///     var.deref()
+    /// });
+    /// assert_eq!(inner, Some(Value::integer_ptr(2)));
+    /// assert_eq!(var.deref(), Some(Value::integer_ptr(1)));
+    /// ```
+    pub fn with_thread_bindings<R>(bindings: Vec<(PtrVar, PtrValue)>, f: impl FnOnce() -> R) -> R {
+        struct PopOnDrop;
+        impl Drop for PopOnDrop {
+            fn drop(&mut self) {
+                Var::pop_thread_bindings();
+            }
+        }
+
+        Var::push_thread_bindings(bindings);
+        let _pop_on_drop = PopOnDrop;
+        f()
+    }
+
+    /// Get this variable's innermost thread-local binding on this thread, if any. Only dynamic
+    /// Vars can have one, so the binding frames are only searched for those.
+    pub fn thread_binding(&self) -> Option<PtrValue> {
+        if !self.is_dynamic() {
+            return None;
+        }
+        let var_ptr = self as *const Var;
+        BINDING_FRAMES.with(|frames| {
+            let frames = frames.borrow();
+            if frames.is_empty() {
+                return None;
+            }
+            frames
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter())
+                .find(|(var, _)| Arc::as_ptr(var) == var_ptr)
+                .map(|(_, value)| value.clone())
+        })
     }
 
     /// Get the value, allowing re-entrant access from the same thread.
+    /// A thread-local binding (see [`Var::push_thread_bindings`]) takes precedence over the root value.
     /// Supports Scenario 1: Let bindings where the RHS eval accesses the variable
     /// Supports Scenario 2: Recursive functions accessing their own binding
     pub fn deref(&self) -> Option<PtrValue> {
+        if let Some(value) = self.thread_binding() {
+            return Some(value);
+        }
+
         let var_ptr = self as *const Var as usize;
 
         // Track this variable's access depth for this thread
@@ -733,4 +847,80 @@ mod tests {
             panic!("Expected Value::Var variant");
         }
     }
+
+    fn dynamic_var(value: PtrValue) -> PtrVar {
+        let meta = Map::new(vec![(
+            Value::keyword_unqualified_ptr("dynamic"),
+            Value::boolean_ptr(true),
+        )]);
+        Arc::new(Var::new_bound_with_meta(value, Some(Arc::new(meta))))
+    }
+
+    #[test]
+    fn is_dynamic_reads_meta() {
+        assert!(!Var::new_unbound().is_dynamic());
+        assert!(dynamic_var(Value::nil_ptr()).is_dynamic());
+    }
+
+    #[test]
+    fn is_macro_and_takes_form_env_read_meta() {
+        let var = Var::new_unbound();
+        assert!(!var.is_macro());
+        assert!(!var.takes_form_env());
+        var.assoc_meta(
+            Value::keyword_unqualified_ptr("macro"),
+            Value::boolean_ptr(true),
+        );
+        assert!(var.is_macro());
+        assert!(!var.takes_form_env());
+        var.assoc_meta(
+            Value::keyword_qualified_ptr("jinme", "form-env"),
+            Value::boolean_ptr(true),
+        );
+        assert!(var.takes_form_env());
+    }
+
+    #[test]
+    fn thread_bindings_shadow_root_value() {
+        let var = dynamic_var(Value::integer_ptr(1));
+        Var::with_thread_bindings(vec![(var.clone(), Value::integer_ptr(2))], || {
+            assert_eq!(var.deref(), Some(Value::integer_ptr(2)));
+            Var::with_thread_bindings(vec![(var.clone(), Value::integer_ptr(3))], || {
+                assert_eq!(var.deref(), Some(Value::integer_ptr(3)));
+            });
+            assert_eq!(var.deref(), Some(Value::integer_ptr(2)));
+        });
+        assert_eq!(var.deref(), Some(Value::integer_ptr(1)));
+    }
+
+    #[test]
+    fn thread_bindings_are_thread_local() {
+        let var = dynamic_var(Value::integer_ptr(1));
+        Var::with_thread_bindings(vec![(var.clone(), Value::integer_ptr(2))], || {
+            let other_var = var.clone();
+            let seen = std::thread::spawn(move || other_var.deref())
+                .join()
+                .unwrap();
+            assert_eq!(seen, Some(Value::integer_ptr(1)));
+        });
+    }
+
+    #[test]
+    fn thread_bindings_popped_on_panic() {
+        let var = dynamic_var(Value::integer_ptr(1));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            Var::with_thread_bindings(vec![(var.clone(), Value::integer_ptr(2))], || {
+                panic!("boom");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(var.deref(), Some(Value::integer_ptr(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot dynamically bind non-dynamic Var")]
+    fn thread_bindings_require_dynamic_var() {
+        let var = Arc::new(Var::new_bound(Value::integer_ptr(1)));
+        Var::push_thread_bindings(vec![(var, Value::integer_ptr(2))]);
+    }
 }
@@ -3,7 +3,7 @@ use ::std::{
     io::{self},
     sync::Arc,
 };
-use jinme::{optics::View as _, prelude::*};
+use jinme::{optics::View as _, prelude::*, read2::TryRead as _};
 
 use opentelemetry::{global, trace::TracerProvider as _};
 use opentelemetry_otlp::{SpanExporter, WithExportConfig};
@@ -172,6 +172,8 @@ fn create_env() -> PtrEnvironment {
 
     let clojure_core = Namespace::new_empty_ptr("clojure.core");
     clojure_core.bind_value("*ns*", Value::handle(Handle::new(clojure_core.clone())));
+    // Functions by tag symbol, for reading #tag form ahead of the reader's own #inst and #uuid
+    clojure_core.bind_value("*data-readers*", Value::new_map_empty());
     env.insert_namespace(clojure_core.clone());
 
     // (defn clojure.core/+ [& xs])
@@ -330,6 +332,328 @@ fn create_env() -> PtrEnvironment {
         )],
     );
 
+    // (clojure.core/string-writer)
+    // Returns a WriteHandle writing into a string buffer, read back with (str writer)
+    clojure_core.build_and_bind_function(
+        "string-writer",
+        vec![closure_fn(
+            FunctionArity::Exactly(0),
+            |_env: PtrEnvironment, _ctx: EvalContext, _args: Vec<PtrValue>| {
+                Value::handle_ptr(Handle::new(WriteHandle::new_string_writer()))
+            },
+        )],
+    );
+
+    // (clojure.core/string-reader s)
+    // Returns a BufReadHandle reading from the string s, e.g. to bind *in* to
+    clojure_core.build_and_bind_function(
+        "string-reader",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let string = string_arg("string-reader", &args[0]);
+                Value::handle_ptr(Handle::new(BufReadHandle::new_string_reader(string)))
+            },
+        )],
+    );
+
+    // (clojure.core/read)
+    // (clojure.core/read stream)
+    // (clojure.core/read opts stream)
+    // (clojure.core/read stream eof-error? eof-value)
+    // Reads the next form from stream, or *in*. At the end of the input, gives the :eof option
+    // or eof-value if there is one, otherwise fails
+    clojure_core.build_and_bind_function(
+        "read",
+        vec![
+            closure_fn(
+                FunctionArity::Exactly(0),
+                |env: PtrEnvironment, _ctx: EvalContext, _args: Vec<PtrValue>| {
+                    let ns = env.get_namespace_or_panic("clojure.core");
+                    let stream = reader_arg("read", &ns.get_value_or_panic("*in*"));
+                    read_form("read", env, &stream, None)
+                },
+            ),
+            closure_fn(
+                FunctionArity::Exactly(1),
+                |env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    read_form("read", env, &reader_arg("read", &args[0]), None)
+                },
+            ),
+            closure_fn(
+                FunctionArity::Exactly(2),
+                |env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let eof = eof_option("read", &args[0]);
+                    read_form("read", env, &reader_arg("read", &args[1]), eof)
+                },
+            ),
+            closure_fn(
+                FunctionArity::Exactly(3),
+                |env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let eof = (!args[1].is_truthy()).then(|| args[2].clone());
+                    read_form("read", env, &reader_arg("read", &args[0]), eof)
+                },
+            ),
+        ],
+    );
+
+    // (clojure.core/read-line)
+    // Reads the next line from *in*, without its line ending, or nil at the end of the input
+    clojure_core.build_and_bind_function(
+        "read-line",
+        vec![closure_fn(
+            FunctionArity::Exactly(0),
+            |env: PtrEnvironment, _ctx: EvalContext, _args: Vec<PtrValue>| {
+                let ns = env.get_namespace_or_panic("clojure.core");
+                let stream = reader_arg("read-line", &ns.get_value_or_panic("*in*"));
+                match stream.read_line() {
+                    Ok(Some(line)) => Value::string_ptr(line),
+                    Ok(None) => Value::nil_ptr(),
+                    Err(err) => panic!("clojure.core/read-line: failed to read input: {}", err),
+                }
+            },
+        )],
+    );
+
+    // (clojure.core/read-string s)
+    // (clojure.core/read-string opts s)
+    // Reads the first form in the string s. If there is none, gives the :eof option if there is
+    // one, otherwise fails
+    clojure_core.build_and_bind_function(
+        "read-string",
+        vec![
+            closure_fn(
+                FunctionArity::Exactly(1),
+                |env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let stream = BufReadHandle::new_string_reader(string_arg("read-string", &args[0]));
+                    read_form("read-string", env, &stream, None)
+                },
+            ),
+            closure_fn(
+                FunctionArity::Exactly(2),
+                |env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let eof = eof_option("read-string", &args[0]);
+                    let stream = BufReadHandle::new_string_reader(string_arg("read-string", &args[1]));
+                    read_form("read-string", env, &stream, eof)
+                },
+            ),
+        ],
+    );
+
+    // (clojure.core/str & xs)
+    // Concatenates xs: nil is empty, strings and chars are unquoted, string writers give their contents
+    // and regexes their patterns
+    clojure_core.build_and_bind_function(
+        "str",
+        vec![closure_fn(
+            FunctionArity::AtLeast(0),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let mut string = String::new();
+                for arg in args.iter() {
+                    match arg.as_ref() {
+                        Value::Nil(_) => {}
+                        Value::String(..) => string += &value::optics::preview_string(arg.as_ref()).unwrap(),
+                        Value::Char(c, _) => string.push(*c),
+                        Value::Handle(handle, _) => match handle
+                            .downcast_ref::<WriteHandle>()
+                            .and_then(|writer| writer.string_contents())
+                            .or_else(|| handle.downcast_ref::<RegexHandle>().map(|regex| regex.as_str().to_owned()))
+                        {
+                            Some(contents) => string += &contents,
+                            None => string += &arg.to_string(),
+                        },
+                        _ => string += &arg.to_string(),
+                    }
+                }
+                Value::string_ptr(string)
+            },
+        )],
+    );
+
+    // (clojure.core/char x)
+    // The character with code point x, or x itself if it is already a character
+    clojure_core.build_and_bind_function(
+        "char",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| match args[0].as_ref() {
+                Value::Char(..) => args[0].clone(),
+                Value::Integer(code_point, _) => u32::try_from(*code_point)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .map(Value::char_ptr)
+                    .unwrap_or_else(|| panic!("clojure.core/char: value out of range for char: {}", code_point)),
+                _ => panic!("clojure.core/char requires an integer or a character, but got: {}", args[0]),
+            },
+        )],
+    );
+
+    // (clojure.core/int x)
+    // The code point of a character, or a number truncated to an integer
+    clojure_core.build_and_bind_function(
+        "int",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| match args[0].as_ref() {
+                Value::Char(c, _) => Value::integer_ptr(*c as i64),
+                Value::Integer(..) => args[0].clone(),
+                Value::Float(float, _) => Value::integer_ptr(float.as_f64() as i64),
+                _ => panic!("clojure.core/int requires a number or a character, but got: {}", args[0]),
+            },
+        )],
+    );
+
+    // (clojure.core/re-pattern s)
+    // A regex compiled from the string s, or s itself if it is already a regex
+    clojure_core.build_and_bind_function(
+        "re-pattern",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                if args[0].try_get_handle_ref::<RegexHandle>().is_ok() {
+                    return args[0].clone();
+                }
+                let pattern = value::optics::preview_string(args[0].as_ref())
+                    .unwrap_or_else(|| panic!("clojure.core/re-pattern requires a string, but got: {}", args[0]));
+                let regex = RegexHandle::new(&pattern)
+                    .unwrap_or_else(|message| panic!("clojure.core/re-pattern: invalid regex: {}", message));
+                Value::handle_ptr(Handle::new(regex))
+            },
+        )],
+    );
+
+    // (clojure.core/re-matcher re s)
+    // A matcher for successive matches of re in s, for re-find and re-groups
+    clojure_core.build_and_bind_function(
+        "re-matcher",
+        vec![closure_fn(
+            FunctionArity::Exactly(2),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let regex = regex_arg("re-matcher", &args[0]);
+                let input = string_arg("re-matcher", &args[1]);
+                Value::handle_ptr(Handle::new(RegexMatcherHandle::new(regex, input)))
+            },
+        )],
+    );
+
+    // (clojure.core/re-find m)
+    // (clojure.core/re-find re s)
+    // The next match of matcher m, or the first match of re in s, or nil if there is none.
+    // A match is the matched string, or a vector of it and its groups if re has groups
+    clojure_core.build_and_bind_function(
+        "re-find",
+        vec![
+            closure_fn(
+                FunctionArity::Exactly(1),
+                |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let mut found = None;
+                    value::optics::preview_handle_ref(args[0].as_ref())
+                        .and_then(|handle| handle.downcast_mut(|matcher: &mut RegexMatcherHandle| found = matcher.find()))
+                        .unwrap_or_else(|| panic!("clojure.core/re-find requires a matcher, but got: {}", args[0]));
+                    found.map(|found| found.to_value()).unwrap_or_else(Value::nil_ptr)
+                },
+            ),
+            closure_fn(
+                FunctionArity::Exactly(2),
+                |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let regex = regex_arg("re-find", &args[0]);
+                    let input = string_arg("re-find", &args[1]);
+                    regex.find_at(&input, 0).map(|found| found.to_value()).unwrap_or_else(Value::nil_ptr)
+                },
+            ),
+        ],
+    );
+
+    // (clojure.core/re-matches re s)
+    // The match of re against the whole of s, or nil if it doesn't match all of it
+    clojure_core.build_and_bind_function(
+        "re-matches",
+        vec![closure_fn(
+            FunctionArity::Exactly(2),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let regex = regex_arg("re-matches", &args[0]);
+                let input = string_arg("re-matches", &args[1]);
+                regex.matches(&input).map(|found| found.to_value()).unwrap_or_else(Value::nil_ptr)
+            },
+        )],
+    );
+
+    // (clojure.core/re-seq re s)
+    // A list of the successive matches of re in s, or nil if there are none
+    clojure_core.build_and_bind_function(
+        "re-seq",
+        vec![closure_fn(
+            FunctionArity::Exactly(2),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let regex = regex_arg("re-seq", &args[0]);
+                let input = string_arg("re-seq", &args[1]);
+                let mut matcher = RegexMatcherHandle::new(regex, input);
+                let found = ::std::iter::from_fn(|| matcher.find()).map(|found| found.to_value()).collect::<Vec<_>>();
+                if found.is_empty() { Value::nil_ptr() } else { Value::new_list_ptr(found) }
+            },
+        )],
+    );
+
+    // (clojure.core/re-groups m)
+    // The most recent match of matcher m, as re-find returned it; panics if there is none
+    clojure_core.build_and_bind_function(
+        "re-groups",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                value::optics::preview_handle_ref(args[0].as_ref())
+                    .and_then(|handle| handle.with_downcast_ref(|matcher: &RegexMatcherHandle| matcher.last_match().map(RegexMatch::to_value)))
+                    .unwrap_or_else(|| panic!("clojure.core/re-groups requires a matcher, but got: {}", args[0]))
+                    .unwrap_or_else(|| panic!("clojure.core/re-groups: no match found"))
+            },
+        )],
+    );
+
+    // (clojure.core/tagged-literal tag form)
+    // The tagged literal #tag form, as read when tag has no reader
+    clojure_core.build_and_bind_function(
+        "tagged-literal",
+        vec![closure_fn(
+            FunctionArity::Exactly(2),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let tag = value::optics::preview_symbol(args[0].as_ref())
+                    .unwrap_or_else(|| panic!("clojure.core/tagged-literal requires a symbol tag, but got: {}", args[0]));
+                Value::tagged_ptr(TaggedLiteral::new(tag, args[1].clone()))
+            },
+        )],
+    );
+
+    // (clojure.core/tagged-literal? x)
+    clojure_core.build_and_bind_function(
+        "tagged-literal?",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| Value::boolean_ptr(args[0].is_tagged()),
+        )],
+    );
+
+    // (clojure.core/inst? x)
+    clojure_core.build_and_bind_function(
+        "inst?",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                Value::boolean_ptr(value::optics::preview_tagged_ref(args[0].as_ref()).is_some_and(TaggedLiteral::is_inst))
+            },
+        )],
+    );
+
+    // (clojure.core/uuid? x)
+    clojure_core.build_and_bind_function(
+        "uuid?",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                Value::boolean_ptr(value::optics::preview_tagged_ref(args[0].as_ref()).is_some_and(TaggedLiteral::is_uuid))
+            },
+        )],
+    );
+
     // (clojure.core/symbol name)
     // (clojure.core/symbol ns_name name)
     clojure_core.build_and_bind_function(
@@ -420,6 +744,64 @@ fn create_env() -> PtrEnvironment {
         )],
     );
 
+    // (clojure.core/ex-info msg map)
+    // Returns map with msg as its :jinme.anomalies/message and, unless map already has one,
+    // :fault as its :jinme.anomalies/category, ready to be thrown
+    clojure_core.build_and_bind_function(
+        "ex-info",
+        vec![closure_fn(
+            FunctionArity::Exactly(2),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let message = value::optics::preview_string(args[0].as_ref())
+                    .expect("clojure.core/ex-info first argument must be a string message");
+                let mut data = match args[1].as_ref() {
+                    Value::Nil(_) => Map::new_empty(),
+                    Value::Map(map, _) => map.to_owned(),
+                    _ => panic!("clojure.core/ex-info second argument must be a map, but got: {}", args[1]),
+                };
+                let category_key = Value::keyword_qualified_ptr("jinme.anomalies", "category");
+                if !data.contains_key(&category_key) {
+                    data.insert(category_key, Value::keyword_unqualified_ptr("fault"));
+                }
+                data.insert(
+                    Value::keyword_qualified_ptr("jinme.anomalies", "message"),
+                    Value::string_ptr(message),
+                );
+                Value::map_ptr(data)
+            },
+        )],
+    );
+
+    // (clojure.core/ex-data ex)
+    // Returns ex if it is an ex-info map, otherwise nil
+    clojure_core.build_and_bind_function(
+        "ex-data",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                match jinme::core::preview_ex_info(args[0].as_ref()) {
+                    Some(_) => args[0].clone(),
+                    None => Value::nil_ptr(),
+                }
+            },
+        )],
+    );
+
+    // (clojure.core/ex-message ex)
+    // Returns the :jinme.anomalies/message of ex if it is an ex-info map, otherwise nil
+    clojure_core.build_and_bind_function(
+        "ex-message",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                match jinme::core::preview_ex_info(args[0].as_ref()) {
+                    Some(anomaly) => Value::string_ptr(anomaly.get_message()),
+                    None => Value::nil_ptr(),
+                }
+            },
+        )],
+    );
+
     clojure_core.build_and_bind_function(
         "list",
         vec![closure_fn(
@@ -428,6 +810,78 @@ fn create_env() -> PtrEnvironment {
         )],
     );
 
+    // (clojure.core/seq coll)
+    // nil if coll is empty, otherwise a list of its elements
+    clojure_core.build_and_bind_function(
+        "seq",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let elements = coll_elements("clojure.core/seq", &args[0]);
+                if elements.is_empty() { Value::nil_ptr() } else { Value::new_list_ptr(elements) }
+            },
+        )],
+    );
+
+    // (clojure.core/concat & colls)
+    clojure_core.build_and_bind_function(
+        "concat",
+        vec![closure_fn(
+            FunctionArity::AtLeast(0),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                Value::new_list_ptr(args.iter().flat_map(|coll| coll_elements("clojure.core/concat", coll)).collect())
+            },
+        )],
+    );
+
+    // (clojure.core/vec coll)
+    clojure_core.build_and_bind_function(
+        "vec",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                Value::new_vector_ptr(coll_elements("clojure.core/vec", &args[0]))
+            },
+        )],
+    );
+
+    // (clojure.core/set coll)
+    clojure_core.build_and_bind_function(
+        "set",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                Value::new_set_ptr(coll_elements("clojure.core/set", &args[0]))
+            },
+        )],
+    );
+
+    // (clojure.core/gensym)
+    // (clojure.core/gensym prefix)
+    // A new unqualified symbol, unique across all gensyms (including auto-gensyms in syntax-quote)
+    clojure_core.build_and_bind_function(
+        "gensym",
+        vec![
+            closure_fn(
+                FunctionArity::Exactly(0),
+                |_env: PtrEnvironment, _ctx: EvalContext, _args: Vec<PtrValue>| {
+                    Value::symbol_ptr(Symbol::gensym("G__"))
+                },
+            ),
+            closure_fn(
+                FunctionArity::Exactly(1),
+                |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                    let prefix = match args[0].as_ref() {
+                        Value::String(prefix, _) => prefix.to_owned(),
+                        Value::Symbol(prefix, _) => prefix.to_string(),
+                        _ => panic!("clojure.core/gensym prefix must be a string or symbol, but got: {}", args[0]),
+                    };
+                    Value::symbol_ptr(Symbol::gensym(&prefix))
+                },
+            ),
+        ],
+    );
+
     // (clojure.core/all-ns)
     clojure_core.build_and_bind_function(
         "all-ns",
@@ -1135,6 +1589,7 @@ fn create_env() -> PtrEnvironment {
             |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| match args[0].as_ref() {
                 Value::List(list, _) => list.get_first().unwrap_or_else(Value::nil_ptr),
                 Value::Vector(vec, _) => vec.get_first().unwrap_or_else(Value::nil_ptr),
+                Value::String(string, _) => string.chars().next().map(Value::char_ptr).unwrap_or_else(Value::nil_ptr),
                 _ => Value::nil_ptr(),
             },
         )],
@@ -1148,6 +1603,7 @@ fn create_env() -> PtrEnvironment {
             |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| match args[0].as_ref() {
                 Value::List(list, _) => list.get_second().unwrap_or_else(Value::nil_ptr),
                 Value::Vector(vec, _) => vec.get_second().unwrap_or_else(Value::nil_ptr),
+                Value::String(string, _) => string.chars().nth(1).map(Value::char_ptr).unwrap_or_else(Value::nil_ptr),
                 _ => Value::nil_ptr(),
             },
         )],
@@ -1161,6 +1617,7 @@ fn create_env() -> PtrEnvironment {
             |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| match args[0].as_ref() {
                 Value::List(list, _) => list.get_last().unwrap_or_else(Value::nil_ptr),
                 Value::Vector(vec, _) => vec.get_last().unwrap_or_else(Value::nil_ptr),
+                Value::String(string, _) => string.chars().last().map(Value::char_ptr).unwrap_or_else(Value::nil_ptr),
                 _ => Value::nil_ptr(),
             },
         )],
@@ -1182,8 +1639,22 @@ fn create_env() -> PtrEnvironment {
         )],
     );
 
-    // (clojure.core/fn name-or-params & forms) -> (fn* name-or-params & forms)
-    // Supports: (fn [x] x) and (fn my-fn [x] x)
+    // (clojure.core/loop bindings & body) -> (loop* bindings & body)
+    clojure_core.build_and_bind_macro(
+        "loop",
+        vec![closure_fn(
+            FunctionArity::AtLeast(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let loop_star_symbol = Value::symbol_ptr(Symbol::new_unqualified("loop*"));
+                let mut elements = vec![loop_star_symbol];
+                elements.extend(args);
+                Value::list_ptr(List::from(elements))
+            },
+        )],
+    );
+
+    // (clojure.core/fn name-or-params & forms) -> (fn* name-or-params & forms)
+    // Supports: (fn [x] x) and (fn my-fn [x] x)
     clojure_core.build_and_bind_macro(
         "fn",
         vec![closure_fn(
@@ -1201,6 +1672,180 @@ fn create_env() -> PtrEnvironment {
         )],
     );
 
+    // (clojure.core/macroexpand-1 form)
+    clojure_core.build_and_bind_function(
+        "macroexpand-1",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>| {
+                jinme::core::macroexpand_1(env, ctx, args[0].clone())
+            },
+        )],
+    );
+
+    // (clojure.core/macroexpand form)
+    clojure_core.build_and_bind_function(
+        "macroexpand",
+        vec![closure_fn(
+            FunctionArity::Exactly(1),
+            |env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>| {
+                jinme::core::macroexpand(env, ctx, args[0].clone())
+            },
+        )],
+    );
+
+    // (clojure.core/letfn [(name params & body) ...] & body)
+    //   -> (letfn* [name (fn* name params & body) ...] & body)
+    clojure_core.build_and_bind_macro(
+        "letfn",
+        vec![closure_fn(
+            FunctionArity::AtLeast(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let Value::Vector(fn_specs, _) = args[0].as_ref() else {
+                    panic!("clojure.core/letfn expects a vector of function specs, but got: {}", args[0]);
+                };
+                let mut bindings = vec![];
+                for fn_spec in fn_specs.iter() {
+                    let (name, spec) = match fn_spec.as_ref() {
+                        Value::List(spec, _) if spec.get_first().is_some_and(|name| name.is_symbol()) => {
+                            (spec.get_first().unwrap(), spec)
+                        }
+                        _ => panic!("clojure.core/letfn function spec must be a list starting with a name, but got: {}", fn_spec),
+                    };
+                    let mut fn_star = vec![Value::symbol_ptr(Symbol::new_unqualified("fn*"))];
+                    fn_star.extend(spec.iter().cloned());
+                    bindings.push(name);
+                    bindings.push(Value::list_ptr(List::from(fn_star)));
+                }
+                let letfn_star_symbol = Value::symbol_ptr(Symbol::new_unqualified("letfn*"));
+                let mut elements = vec![letfn_star_symbol, Value::new_vector_ptr(bindings)];
+                elements.extend(args.into_iter().skip(1));
+                Value::list_ptr(List::from(elements))
+            },
+        )],
+    );
+
+    // (clojure.core/push-thread-bindings {var value ,,,})
+    // Binds each dynamic var to its value on this thread, until the matching pop-thread-bindings
+    clojure_core.build_and_bind_function(
+        "push-thread-bindings",
+        vec![(FunctionArity::Exactly(1), Arc::new(PushThreadBindings))],
+    );
+
+    // (clojure.core/pop-thread-bindings)
+    // Pops the bindings of the innermost push-thread-bindings on this thread
+    clojure_core.build_and_bind_function(
+        "pop-thread-bindings",
+        vec![closure_fn(
+            FunctionArity::Exactly(0),
+            |_env: PtrEnvironment, _ctx: EvalContext, _args: Vec<PtrValue>| {
+                Var::pop_thread_bindings();
+                Value::nil_ptr()
+            },
+        )],
+    );
+
+    // (clojure.core/binding [var-sym expr ,,,] & body)
+    //   -> (do (push-thread-bindings {(var var-sym) expr ,,,})
+    //          (try (do & body) (finally (pop-thread-bindings))))
+    // Evaluates the body with each dynamic var bound to the value of its expr on this thread
+    clojure_core.build_and_bind_macro(
+        "binding",
+        vec![closure_fn(
+            FunctionArity::AtLeast(1),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let Value::Vector(bindings, _) = args[0].as_ref() else {
+                    panic!("clojure.core/binding expects a vector of bindings, but got: {}", args[0]);
+                };
+                if !bindings.len().is_multiple_of(2) {
+                    panic!("clojure.core/binding expects an even number of forms in its bindings, but got: {}", args[0]);
+                }
+                let core_symbol = |name: &str| Value::symbol_qualified_ptr("clojure.core", name);
+                let mut var_values = vec![];
+                for pair in bindings.iter().collect::<Vec<_>>().chunks(2) {
+                    if !pair[0].is_symbol() {
+                        panic!("clojure.core/binding binding name must be a symbol, but got: {}", pair[0]);
+                    }
+                    var_values.push((
+                        Value::new_list_ptr(vec![Value::symbol_unqualified_ptr("var"), pair[0].to_owned()]),
+                        pair[1].to_owned(),
+                    ));
+                }
+                let mut body = vec![Value::symbol_unqualified_ptr("do")];
+                body.extend(args.into_iter().skip(1));
+                Value::new_list_ptr(vec![
+                    Value::symbol_unqualified_ptr("do"),
+                    Value::new_list_ptr(vec![core_symbol("push-thread-bindings"), Value::map_ptr(Map::new(var_values))]),
+                    Value::new_list_ptr(vec![
+                        Value::symbol_unqualified_ptr("try"),
+                        Value::new_list_ptr(body),
+                        Value::new_list_ptr(vec![
+                            Value::symbol_unqualified_ptr("finally"),
+                            Value::new_list_ptr(vec![core_symbol("pop-thread-bindings")]),
+                        ]),
+                    ]),
+                ])
+            },
+        )],
+    );
+
+    // (clojure.core/defmacro name doc-string? [params*] body)
+    // (clojure.core/defmacro name doc-string? ([params*] body) +)
+    //   -> (def ^{:macro true, :jinme/form-env true} name doc-string?
+    //        (fn* name [&form &env params*] body))
+    // The expander receives the macro call form as &form and a map of the locals in scope as &env.
+    clojure_core.build_and_bind_macro(
+        "defmacro",
+        vec![closure_fn(
+            FunctionArity::AtLeast(2),
+            |_env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>| {
+                let Value::Symbol(_, name_meta) = args[0].as_ref() else {
+                    panic!("clojure.core/defmacro requires a symbol name, but got: {}", args[0]);
+                };
+                let name_meta = name_meta
+                    .assoc(Value::keyword_unqualified_ptr("macro"), Value::boolean_ptr(true))
+                    .assoc(Value::keyword_qualified_ptr("jinme", "form-env"), Value::boolean_ptr(true));
+                let name = args[0].with_meta_ptr(name_meta);
+
+                let mut rest = args[1..].to_vec();
+                let docstring = if rest.len() > 1 && rest[0].is_string() { Some(rest.remove(0)) } else { None };
+
+                let with_form_env = |params: &PtrValue| -> PtrValue {
+                    let Value::Vector(params, _) = params.as_ref() else {
+                        panic!("clojure.core/defmacro parameters must be a vector, but got: {}", params);
+                    };
+                    let mut elements = vec![Value::symbol_unqualified_ptr("&form"), Value::symbol_unqualified_ptr("&env")];
+                    elements.extend(params.iter().cloned());
+                    Value::new_vector_ptr(elements)
+                };
+                let mut fn_star = vec![Value::symbol_unqualified_ptr("fn*"), args[0].with_meta_ptr(None)];
+                match rest.first().map(PtrValue::as_ref) {
+                    Some(Value::Vector(_, _)) => {
+                        fn_star.push(with_form_env(&rest[0]));
+                        fn_star.extend(rest.into_iter().skip(1));
+                    }
+                    Some(Value::List(_, _)) => {
+                        for arity in rest {
+                            let Value::List(arity, _) = arity.as_ref() else {
+                                panic!("clojure.core/defmacro arities must be lists, but got: {}", arity);
+                            };
+                            let params = arity.get_first().unwrap_or_else(Value::nil_ptr);
+                            let mut elements = vec![with_form_env(&params)];
+                            elements.extend(arity.iter().skip(1).cloned());
+                            fn_star.push(Value::new_list_ptr(elements));
+                        }
+                    }
+                    _ => panic!("clojure.core/defmacro requires a parameter vector or arities after the name"),
+                }
+
+                let mut def = vec![Value::symbol_unqualified_ptr("def"), name];
+                def.extend(docstring);
+                def.push(Value::new_list_ptr(fn_star));
+                Value::new_list_ptr(def)
+            },
+        )],
+    );
+
     bind_stdioe(
         clojure_core.as_ref(),
         "*in*",
@@ -1250,8 +1895,13 @@ fn eval_string(bin_call: &str, args: Vec<String>) {
     let args = args.into_iter().skip(1).collect();
     let env = create_env();
     add_jinme_core(env.clone(), args);
-    let read_output = read(env.clone(), string.as_str()).expect("failed to read string");
-    let read_value = read_output.1.expect("no value read from string");
+    let read_value = match read(env.clone(), string.as_str()) {
+        Ok((_, read_value)) => read_value.expect("no value read from string"),
+        Err(err) => {
+            eprintln!("Error reading string: {}", err);
+            std::process::exit(1);
+        }
+    };
     let evaled = eval(env.clone(), EvalContext::new_empty(), read_value);
     println!("{evaled}");
 }
@@ -1278,6 +1928,7 @@ fn eval_file(bin_call: &str, args: Vec<String>) {
     ));
     let file_contents =
         std::fs::read_to_string(file_path).expect(&format!("failed to read file: {}", file_path));
+    let reader = read2::Reader::with_source(&file_contents, Some(file_path.to_owned()));
 
     let args = args.into_iter().skip(1).collect();
     let env = create_env();
@@ -1292,23 +1943,31 @@ fn eval_file(bin_call: &str, args: Vec<String>) {
             break;
         }
 
-        match read(env.clone(), remaining) {
+        match reader.try_read(env.clone(), remaining) {
             Ok((next_remaining, Some(value))) => {
-                last_result = eval(env.clone(), EvalContext::new_empty(), value);
+                match try_eval(env.clone(), EvalContext::new_empty(), value) {
+                    Ok(value) => last_result = value,
+                    Err(err) => {
+                        match (err.position(), calculate_offset(&file_contents, remaining)) {
+                            (Some(position), _) => {
+                                eprintln!("Error evaluating form at {}", position)
+                            }
+                            (None, Some(offset)) => {
+                                eprintln!("Error evaluating form at byte offset: {}", offset)
+                            }
+                            (None, None) => eprintln!("Error evaluating form"),
+                        }
+                        eprintln!("Error: {}", err.to_anomaly_map().inner());
+                        break;
+                    }
+                }
                 remaining = next_remaining;
             }
             Ok((_, None)) => {
                 break;
             }
             Err(err) => {
-                // eprintln!("Error reading file at position: {}", remaining.len());
-                match calculate_offset(&file_contents, remaining) {
-                    Some(offset) => eprintln!("Error reading file at byte offset: {}", offset),
-                    None => eprintln!(
-                        "Error reading file: remaining slice is not within original file contents"
-                    ),
-                }
-                eprintln!("Error: {:?}", err);
+                eprintln!("Error reading file: {}", err);
                 break;
             }
         }
@@ -1340,8 +1999,7 @@ fn read_string(bin_call: &str, args: Vec<String>) {
             println!("<no value read>");
         }
         Err(err) => {
-            eprintln!("Error reading string:");
-            eprintln!("{}", err.into_inner());
+            eprintln!("Error reading string: {}", err);
         }
     }
 }
@@ -1506,6 +2164,100 @@ fn demo_optics(_bin_call: &str, args: Vec<String>) {
     // println!("----------------------------------------------------------");
 }
 
+/// The regex `value` holds, for the regex builtins; panics if it isn't one.
+fn regex_arg(fn_name: &str, value: &PtrValue) -> RegexHandle {
+    value
+        .try_get_handle_ref::<RegexHandle>()
+        .unwrap_or_else(|_| panic!("clojure.core/{} requires a regex, but got: {}", fn_name, value))
+}
+
+/// The string `value` holds, for builtins taking strings; panics if it isn't one.
+fn string_arg(fn_name: &str, value: &PtrValue) -> String {
+    value::optics::preview_string(value.as_ref())
+        .unwrap_or_else(|| panic!("clojure.core/{} requires a string, but got: {}", fn_name, value))
+}
+
+/// The input stream `value` holds, for the read builtins; panics if it isn't one.
+fn reader_arg(fn_name: &str, value: &PtrValue) -> BufReadHandle {
+    value
+        .try_get_handle_ref::<BufReadHandle>()
+        .map(|reader| reader.to_owned())
+        .unwrap_or_else(|_| panic!("clojure.core/{} requires an input stream, but got: {}", fn_name, value))
+}
+
+/// The value of the `:eof` option in `opts`, given at the end of the input in place of a form,
+/// or `None` if there isn't one and the end of the input is an error.
+fn eof_option(fn_name: &str, opts: &PtrValue) -> Option<PtrValue> {
+    match opts.as_ref() {
+        Value::Nil(_) => None,
+        Value::Map(map, _) => map.get(&Value::keyword_unqualified_ptr("eof")),
+        _ => panic!("clojure.core/{} requires an options map, but got: {}", fn_name, opts),
+    }
+}
+
+/// Reads the next form from `stream` for the read builtins, giving `eof` at the end of the
+/// input if there is one; panics at the end of the input otherwise, or if the form can't be read.
+fn read_form(fn_name: &str, env: PtrEnvironment, stream: &BufReadHandle, eof: Option<PtrValue>) -> PtrValue {
+    match stream.read_form(&read2::Reader::new(), env) {
+        Ok(Some(form)) => form,
+        Ok(None) => eof.unwrap_or_else(|| panic!("clojure.core/{}: EOF while reading", fn_name)),
+        Err(ReadError::Incomplete(anomaly)) => {
+            panic!("clojure.core/{}: EOF while reading, {}", fn_name, anomaly.get_message())
+        }
+        Err(err) => panic!("clojure.core/{}: {}", fn_name, err.to_anomaly_map().get_message()),
+    }
+}
+
+/// `push-thread-bindings`, which fails with an [`EvalError`] rather than panicking when asked to
+/// bind a var that isn't dynamic.
+struct PushThreadBindings;
+
+impl IFunction for PushThreadBindings {
+    fn invoke(&self, env: PtrEnvironment, ctx: EvalContext, args: Vec<PtrValue>) -> PtrValue {
+        self.try_invoke(env, ctx, args).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_invoke(&self, _env: PtrEnvironment, _ctx: EvalContext, args: Vec<PtrValue>) -> Result<PtrValue, EvalError> {
+        let Value::Map(bindings, _) = args[0].as_ref() else {
+            return Err(EvalError::incorrect(
+                args[0].to_owned(),
+                format!("clojure.core/push-thread-bindings requires a map of vars to values, but got: {}", args[0]),
+            ));
+        };
+        let mut thread_bindings = vec![];
+        for (var, value) in bindings.iter() {
+            match var.as_ref() {
+                Value::Var(var, _) if var.is_dynamic() => thread_bindings.push((var.to_owned(), value.to_owned())),
+                _ => {
+                    return Err(EvalError::incorrect(
+                        var.to_owned(),
+                        format!("clojure.core/push-thread-bindings: cannot dynamically bind non-dynamic var: {}", var),
+                    ));
+                }
+            }
+        }
+        Var::push_thread_bindings(thread_bindings);
+        Ok(Value::nil_ptr())
+    }
+}
+
+/// The elements of a nil, string (as characters), list, vector, set or map (as `[k v]` entries),
+/// for the sequence builtins; panics on anything else.
+fn coll_elements(fn_name: &str, coll: &PtrValue) -> Vec<PtrValue> {
+    match coll.as_ref() {
+        Value::Nil(_) => vec![],
+        Value::String(string, _) => string.chars().map(Value::char_ptr).collect(),
+        Value::List(list, _) => list.iter().cloned().collect(),
+        Value::Vector(vector, _) => vector.iter().cloned().collect(),
+        Value::Set(set, _) => set.iter().cloned().collect(),
+        Value::Map(map, _) => map
+            .iter()
+            .map(|(k, v)| Value::new_vector_ptr(vec![k.to_owned(), v.to_owned()]))
+            .collect(),
+        _ => panic!("{fn_name} requires a collection, but got: {}", coll),
+    }
+}
+
 fn bind_stdioe(
     ns: &Namespace,
     in_name: &str, // "*in*"
@@ -1519,6 +2271,13 @@ fn bind_stdioe(
     ns.bind_value(in_name, Value::handle(Handle::new(get_in_handle())));
     ns.bind_value(out_name, Value::handle(Handle::new(get_out_handle())));
     ns.bind_value(err_name, Value::handle(Handle::new(get_err_handle())));
+    // dynamic, so that `binding` can redirect them, e.g. (binding [*out* (string-writer)] ...)
+    for name in [in_name, out_name, err_name] {
+        ns.get_var_or_panic(name).assoc_meta(
+            Value::keyword_unqualified_ptr("dynamic"),
+            Value::boolean_ptr(true),
+        );
+    }
     log::info!("Created stdin, stdout, and stderr handles.");
 }
 
@@ -1578,39 +2337,33 @@ fn repl(env: PtrEnvironment) {
         }
 
         pending.push(line.as_str());
-        if contains_unclosed_delimiter(line.as_str()) {
-            write!(stdout, "> ").unwrap();
-            continue;
-        }
 
-        let accumulated_input = pending.accumulated_input().to_owned();
-        if accumulated_input.trim().is_empty() {
-            write!(stdout, "> ").unwrap();
-            pending.clear();
-            continue;
-        }
-        let read_result = read(env.clone(), accumulated_input.as_str());
-        match read_result {
-            Ok(read_output) => {
-                let rest_input = read_output.0.to_owned();
-
-                pending.clear();
-                pending.push(rest_input.as_str());
-
-                if let Some(value) = read_output.1 {
-                    let evaled = env
-                        .get_namespace_or_panic("clojure.core")
-                        .get_function_or_panic("eval")
-                        .invoke(env.clone(), EvalContext::new_empty(), vec![value]);
-                    writeln!(stdout, "{}", evaled).unwrap();
-                    write!(stdout, "> ").unwrap();
+        // evaluate each complete form, keeping an incomplete one pending until the next line
+        loop {
+            let accumulated_input = pending.accumulated_input().to_owned();
+            match read(env.clone(), accumulated_input.as_str()) {
+                Ok((rest_input, Some(value))) => {
+                    pending.clear();
+                    pending.push(rest_input);
+                    match try_eval(env.clone(), EvalContext::new_empty(), value) {
+                        Ok(evaled) => writeln!(stdout, "{}", evaled).unwrap(),
+                        Err(err) => writeln!(stdout, "Eval error: {}", err.to_anomaly_map().inner())
+                            .unwrap(),
+                    }
+                }
+                Ok((_, None)) => {
+                    pending.clear();
+                    break;
+                }
+                Err(ReadError::Incomplete(_)) => break,
+                Err(err) => {
+                    writeln!(stdout, "Read error: {}", err).unwrap();
+                    pending.clear();
+                    break;
                 }
-            }
-            Err(err) => {
-                writeln!(stdout, "Read error: {:?}", err).unwrap();
-                pending.clear();
             }
         }
+        write!(stdout, "> ").unwrap();
 
         if env
             .try_get_namespace("jinme.repl")
@@ -1653,44 +2406,6 @@ impl PendingInput {
     }
 }
 
-fn contains_unclosed_delimiter(s: &str) -> bool {
-    let mut paren = 0;
-    let mut bracket = 0;
-    let mut brace = 0;
-
-    let mut in_string = false;
-    let mut escape = false;
-
-    for ch in s.chars() {
-        if escape {
-            escape = false;
-            continue;
-        }
-
-        if in_string {
-            if ch == '\\' {
-                escape = true;
-            } else if ch == '"' {
-                in_string = false;
-            }
-            continue;
-        }
-
-        match ch {
-            '"' => in_string = true,
-            '(' => paren += 1,
-            ')' => paren -= 1,
-            '[' => bracket += 1,
-            ']' => bracket -= 1,
-            '{' => brace += 1,
-            '}' => brace -= 1,
-            _ => {}
-        }
-    }
-
-    paren > 0 || bracket > 0 || brace > 0
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1704,6 +2419,12 @@ mod tests {
         }
     }
 
+    fn read_eval(env: PtrEnvironment, input: &str) -> PtrValue {
+        let read_output = read(env.clone(), input).expect("failed to read");
+        let value = read_output.1.expect("no value read");
+        eval(env, EvalContext::new_empty(), value)
+    }
+
     #[test]
     fn multi_line_repl_input() {
         // arrange
@@ -1718,11 +2439,11 @@ mod tests {
         let mut value = None;
         while let Some(line) = lines.next() {
             pending.push(ensure_trailing_linefeed(line).to_string().as_str());
-            if contains_unclosed_delimiter(line) {
-                continue;
-            }
             let accumulated_input = pending.accumulated_input().to_owned();
-            let read_output = read(env.clone(), accumulated_input.as_str()).expect("read error");
+            let read_output = match read(env.clone(), accumulated_input.as_str()) {
+                Err(ReadError::Incomplete(_)) => continue,
+                read_result => read_result.expect("read error"),
+            };
             let rest_input_1 = read_output.0.to_owned();
             value = read_output.1;
             pending.clear();
@@ -1751,6 +2472,12 @@ mod tests {
         assert!(resolved.is_err());
     }
 
+    #[test]
+    #[should_panic(expected = "could not find namespace: jinme.cli.missing")]
+    fn resolve_or_panic_panics_with_the_resolve_error() {
+        resolve_or_panic(create_env(), &Symbol::new_qualified("jinme.cli.missing", "my-var"));
+    }
+
     #[test]
     fn try_resolve_unqualified_symbol() {
         // arrange
@@ -2990,4 +3717,800 @@ mod tests {
 
         assert!(result.is_var());
     }
+
+    // The evaluator's special forms are tested in jinme::core; the tests below cover the
+    // clojure.core builtins and macros built on them.
+
+    /// Reads and evaluates each form of `input` in `env`, returning the value of the last one
+    fn try_eval_in(env: PtrEnvironment, input: &str) -> Result<PtrValue, EvalError> {
+        let mut value = Value::nil_ptr();
+        let mut rest = input;
+        while let (next, Some(form)) = read(env.clone(), rest).expect("failed to read") {
+            value = try_eval(env.clone(), EvalContext::new_empty(), form)?;
+            rest = next;
+        }
+        Ok(value)
+    }
+
+    /// Reads and evaluates each form of `input` in a fresh environment, returning the value of the last one
+    fn eval_str(input: &str) -> PtrValue {
+        try_eval_str(input).unwrap_or_else(|err| panic!("{input}: {err}"))
+    }
+
+    fn try_eval_str(input: &str) -> Result<PtrValue, EvalError> {
+        try_eval_in(create_env(), input)
+    }
+
+    fn read_one(input: &str) -> PtrValue {
+        read(create_env(), input).expect("failed to read").1.expect("no value read")
+    }
+
+    // loop tests
+    // `(get {0 false} n true)` is falsey only when n is 0
+
+    #[test]
+    fn loop_macro_expands_to_loop_star() {
+        assert_eq!(eval_str("(macroexpand-1 '(loop [n 3] n))"), read_one("(loop* [n 3] n)"));
+        assert_eq!(
+            eval_str("(loop [n 3] (if (get {0 false} n true) (let [m (- n 1)] (recur m)) :done))"),
+            read_one(":done")
+        );
+    }
+
+    // ex-info tests
+
+    #[test]
+    fn ex_info_builds_anomaly_map() {
+        let ex = eval_str("(ex-info \"boom\" {:answer 42})");
+        let anomaly = jinme::core::preview_ex_info(ex.as_ref()).expect("ex-info should return an ex-info map");
+        assert_eq!(anomaly.get_category(), KeywordUnqualified::new("fault"));
+        assert_eq!(anomaly.get_message(), "boom");
+        assert_eq!(anomaly.inner().get(&Value::keyword_unqualified_ptr("answer")), Some(Value::integer_ptr(42)));
+        assert_eq!(eval_str("(ex-message (ex-info \"boom\" {}))"), Value::string_ptr("boom".to_owned()));
+        assert_eq!(eval_str("(ex-data (ex-info \"boom\" {}))"), eval_str("(ex-info \"boom\" {})"));
+        assert!(eval_str("(ex-data {:answer 42})").is_nil());
+        assert!(eval_str("(ex-message :not-an-ex-info)").is_nil());
+    }
+
+    #[test]
+    #[should_panic(expected = "clojure.core/ex-info second argument must be a map")]
+    fn ex_info_with_non_map_data_panics() {
+        eval_str("(ex-info \"boom\" 1)");
+    }
+
+    // binding tests
+
+    #[test]
+    fn stdio_vars_are_dynamic() {
+        let env = create_env();
+        let clojure_core = env.get_namespace_or_panic("clojure.core");
+        for name in ["*in*", "*out*", "*err*"] {
+            assert!(clojure_core.get_var_or_panic(name).is_dynamic(), "{name} should be dynamic");
+        }
+    }
+
+    #[test]
+    fn binding_redirects_out_to_string_writer() {
+        assert_eq!(
+            eval_str("(let [w (string-writer)] (binding [*out* w] (prn 1 :two) (prn \"three\")) (str w))"),
+            Value::string_ptr("1 :two\n\"three\"\n".to_owned())
+        );
+    }
+
+    #[test]
+    fn binding_is_popped_after_body() {
+        let env = create_env();
+        let out_before = read_eval(env.clone(), "*out*");
+        read_eval(env.clone(), "(binding [*out* (string-writer)] :inside)");
+        assert_eq!(read_eval(env.clone(), "*out*"), out_before);
+
+        assert!(try_eval_in(env.clone(), "(binding [*out* (string-writer)] no-such-var)").is_err());
+        assert_eq!(read_eval(env, "*out*"), out_before);
+    }
+
+    #[test]
+    fn binding_is_visible_to_called_functions() {
+        assert_eq!(
+            eval_str(
+                "(def ^:dynamic *depth* 0)
+                 (def current-depth (fn* [] *depth*))
+                 [(binding [*depth* 1] (binding [*depth* 2] (current-depth))) (current-depth)]"
+            ),
+            read_one("[2 0]")
+        );
+    }
+
+    #[test]
+    fn binding_expands_to_push_and_pop_thread_bindings() {
+        assert_eq!(
+            eval_str("(macroexpand-1 '(binding [*out* w] (prn 1)))"),
+            read_one(
+                "(do (clojure.core/push-thread-bindings {(var *out*) w})
+                     (try (do (prn 1)) (finally (clojure.core/pop-thread-bindings))))"
+            )
+        );
+    }
+
+    #[test]
+    fn binding_non_dynamic_var_is_error() {
+        let err = try_eval_str("(def not-dynamic 1) (binding [not-dynamic 2] not-dynamic)").unwrap_err();
+        assert_eq!(err.category(), &KeywordUnqualified::new("incorrect"));
+        assert!(err.message().contains("non-dynamic"));
+    }
+
+    #[test]
+    #[should_panic(expected = "clojure.core/binding expects an even number of forms in its bindings")]
+    fn binding_with_odd_bindings_panics() {
+        eval_str("(binding [*out*] 1)");
+    }
+
+    #[test]
+    #[should_panic(expected = "clojure.core/binding expects a vector of bindings")]
+    fn binding_without_vector_panics() {
+        eval_str("(binding *out* 1)");
+    }
+
+    #[test]
+    fn binding_catch_finally_and_ampersand_are_ordinary_names() {
+        assert_eq!(
+            eval_str("(def catch 1) (def finally (fn* [x] x)) (def & :amp) (list catch (finally 2) &)"),
+            read_one("(1 2 :amp)")
+        );
+        assert_eq!(
+            eval_str("`(binding catch finally &)"),
+            read_one("(clojure.core/binding clojure.core/catch clojure.core/finally clojure.core/&)")
+        );
+    }
+
+    #[test]
+    fn syntax_quoted_ampersand_and_catch_keep_their_meaning() {
+        assert_eq!(
+            eval_str("(defmacro rest-fn [] `(fn* [x# & more#] more#)) ((rest-fn) 1 2 3)"),
+            read_one("(2 3)")
+        );
+        assert_eq!(
+            eval_str(
+                "(defmacro safely [x] `(try ~x (catch :fault e# :failed) (finally nil)))
+                 (safely (throw (ex-info \"boom\" {})))"
+            ),
+            read_one(":failed")
+        );
+    }
+
+    // letfn tests
+
+    #[test]
+    fn letfn_mutual_recursion() {
+        assert_eq!(
+            eval_str(
+                "(letfn [(my-even [n] (if (get {0 false} n true) (my-odd (+ n -1)) true))
+                         (my-odd [n] (if (get {0 false} n true) (my-even (+ n -1)) false))]
+                   [(my-even 10) (my-odd 7) (my-even 3)])"
+            ),
+            read_one("[true true false]")
+        );
+    }
+
+    // macroexpansion tests
+
+    #[test]
+    fn reader_does_not_expand_macros() {
+        assert_eq!(read_one("(let [x 1] x)"), eval_str("'(let [x 1] x)"));
+    }
+
+    #[test]
+    fn macroexpand_1_expands_once() {
+        assert_eq!(eval_str("(macroexpand-1 '(let [x 1] x))"), read_one("(let* [x 1] x)"));
+        assert_eq!(eval_str("(macroexpand-1 '(+ 1 2))"), read_one("(+ 1 2)"));
+        assert_eq!(eval_str("(macroexpand-1 :not-a-form)"), read_one(":not-a-form"));
+    }
+
+    #[test]
+    fn macroexpand_expands_until_not_a_macro_call() {
+        let env = create_env();
+        env.get_namespace_or_panic("clojure.core").build_and_bind_macro(
+            "my-let",
+            vec![closure_fn(FunctionArity::AtLeast(1), |_env, _ctx, args: Vec<PtrValue>| {
+                let mut elements = vec![Value::symbol_unqualified_ptr("let")];
+                elements.extend(args);
+                Value::new_list_ptr(elements)
+            })],
+        );
+        assert_eq!(read_eval(env.clone(), "(macroexpand-1 '(my-let [x 1] x))"), read_one("(let [x 1] x)"));
+        assert_eq!(read_eval(env.clone(), "(macroexpand '(my-let [x 1] x))"), read_one("(let* [x 1] x)"));
+        assert_eq!(read_eval(env, "(my-let [x 1] x)"), Value::integer_ptr(1));
+    }
+
+    #[test]
+    fn quoted_macro_calls_are_not_expanded() {
+        assert_eq!(eval_str("'(fn [x] x)"), read_one("(fn [x] x)"));
+    }
+
+    #[test]
+    fn locals_shadow_macros() {
+        assert_eq!(eval_str("(let* [let (fn* [x] [:local x])] (let 1))"), read_one("[:local 1]"));
+    }
+
+    #[test]
+    fn macro_expansion_in_tail_position_allows_recur() {
+        assert_eq!(
+            eval_str("(loop [n 3 acc 0] (if (get {0 false} n true) (let [m (+ n -1)] (recur m (+ acc n))) acc))"),
+            Value::integer_ptr(6)
+        );
+    }
+
+    #[test]
+    fn malformed_expansions_are_eval_errors() {
+        let err = try_eval_str("(defmacro bad-if [] '(if)) (bad-if)").unwrap_err();
+        assert_eq!(err.category(), &KeywordUnqualified::new("incorrect"));
+        assert!(err.message().starts_with("if: "));
+    }
+
+    // defmacro tests
+
+    #[test]
+    fn defmacro_defines_a_macro_var() {
+        let env = create_env();
+        read_eval(env.clone(), "(defmacro unless [test then else] (list 'if test else then))");
+        let var = env.get_namespace_or_panic("clojure.core").try_get_var("unless").unwrap();
+        assert!(var.is_macro());
+        assert!(matches!(var.deref().as_deref(), Some(Value::Function(_, _))));
+        assert_eq!(read_eval(env.clone(), "(unless false :yes :no)"), read_one(":yes"));
+        assert_eq!(read_eval(env.clone(), "(unless true (throw (ex-info \"never\" {})) :no)"), read_one(":no"));
+        assert_eq!(read_eval(env, "(macroexpand-1 '(unless a b c))"), read_one("(if a c b)"));
+    }
+
+    #[test]
+    fn defmacro_receives_form_and_env() {
+        assert_eq!(
+            eval_str("(defmacro whole-form [& _] (list 'quote &form)) (whole-form 1 2)"),
+            read_one("(whole-form 1 2)")
+        );
+        let env = create_env();
+        read_eval(env.clone(), "(defmacro local-value [sym] (get &env sym))");
+        assert_eq!(read_eval(env.clone(), "(let [x 42] (local-value x))"), Value::integer_ptr(42));
+        assert_eq!(read_eval(env, "(local-value x)"), Value::nil_ptr());
+    }
+
+    #[test]
+    fn defmacro_with_docstring_and_multiple_arities() {
+        let env = create_env();
+        read_eval(
+            env.clone(),
+            "(defmacro my-or \"Returns the first truthy arg.\" ([] nil) ([x] x) ([x y] (list 'let ['or-x x] (list 'if 'or-x 'or-x (list 'my-or y)))))",
+        );
+        let var = env.get_namespace_or_panic("clojure.core").try_get_var("my-or").unwrap();
+        assert_eq!(
+            var.get_meta(&Value::keyword_unqualified_ptr("doc")),
+            Some(Value::string_ptr("Returns the first truthy arg.".to_owned()))
+        );
+        assert_eq!(read_eval(env.clone(), "(my-or)"), Value::nil_ptr());
+        assert_eq!(read_eval(env, "(my-or nil 3)"), Value::integer_ptr(3));
+    }
+
+    #[test]
+    fn macros_called_with_the_wrong_argument_count_are_errors() {
+        let err = try_eval_str("(defmacro one-arg [x] x) (one-arg 1 2)").unwrap_err();
+        assert_eq!(err.category(), &KeywordUnqualified::new("incorrect"));
+        assert!(err.message().contains("unsupported argument count"), "{}", err.message());
+    }
+
+    // syntax-quote and gensym tests
+
+    #[test]
+    fn syntax_quote_qualifies_symbols_with_current_namespace() {
+        assert_eq!(eval_str("`foo"), Value::symbol_qualified_ptr("clojure.core", "foo"));
+        assert_eq!(eval_str("`other/foo"), Value::symbol_qualified_ptr("other", "foo"));
+        assert_eq!(
+            eval_str("`(if x [1 :k \"s\"] {:a y})"),
+            read_one("(if clojure.core/x [1 :k \"s\"] {:a clojure.core/y})")
+        );
+    }
+
+    #[test]
+    fn unquote_and_unquote_splicing() {
+        assert_eq!(eval_str("(let [x 1 ys [2 3]] `(f ~x ~@ys))"), read_one("(clojure.core/f 1 2 3)"));
+        assert_eq!(eval_str("(let [ys '(2 3)] `[0 ~@ys 4])"), read_one("[0 2 3 4]"));
+        assert_eq!(eval_str("(let [ys nil] `(~@ys))"), Value::nil_ptr());
+        assert_eq!(eval_str("`()"), Value::new_list_ptr(vec![]));
+    }
+
+    #[test]
+    fn unquote_reads_as_unquote_form() {
+        assert_eq!(read_one("~x"), read_one("(clojure.core/unquote x)"));
+        assert_eq!(read_one("~@x"), read_one("(clojure.core/unquote-splicing x)"));
+    }
+
+    #[test]
+    fn unquote_splicing_outside_collection_is_read_error() {
+        read_error("`~@x");
+    }
+
+    #[test]
+    fn auto_gensym_is_consistent_within_one_syntax_quote() {
+        let form = eval_str("`(let [x# 1] x#)");
+        let Value::List(list, _) = form.as_ref() else { panic!("expected a list, got: {}", form) };
+        let Some(Value::Vector(bindings, _)) = list.get_second().as_deref().cloned() else { panic!("expected bindings in: {}", form) };
+        let binding = bindings.get_first().unwrap();
+        let Value::Symbol(Symbol::Unqualified(name), _) = binding.as_ref() else { panic!("expected an unqualified symbol, got: {}", binding) };
+        assert!(name.name().starts_with("x__") && name.name().ends_with("__auto__"));
+        assert_eq!(list.get_last(), Some(binding.clone()));
+
+        let other = eval_str("`x#");
+        assert_ne!(other, binding);
+    }
+
+    #[test]
+    fn syntax_quote_in_defmacro() {
+        let env = create_env();
+        read_eval(env.clone(), "(defmacro my-when [test & body] `(if ~test (do ~@body) nil))");
+        assert_eq!(read_eval(env.clone(), "(my-when true 1 2)"), Value::integer_ptr(2));
+        assert_eq!(read_eval(env.clone(), "(my-when false (throw (ex-info \"never\" {})))"), Value::nil_ptr());
+
+        read_eval(env.clone(), "(defmacro twice [x] `(let [v# ~x] (+ v# v#)))");
+        assert_eq!(read_eval(env, "(let [v 10] (twice (+ v 1)))"), Value::integer_ptr(22));
+    }
+
+    #[test]
+    fn gensym_returns_unique_symbols() {
+        let a = eval_str("(gensym)");
+        let b = eval_str("(gensym)");
+        assert_ne!(a, b);
+        let Value::Symbol(Symbol::Unqualified(a), _) = a.as_ref() else { panic!("expected an unqualified symbol, got: {}", a) };
+        assert!(a.name().starts_with("G__"));
+        let prefixed = eval_str("(gensym \"tmp\")");
+        let Value::Symbol(Symbol::Unqualified(prefixed), _) = prefixed.as_ref() else { panic!("expected an unqualified symbol, got: {}", prefixed) };
+        assert!(prefixed.name().starts_with("tmp"));
+    }
+
+    #[test]
+    fn sequence_builtins() {
+        assert_eq!(eval_str("(concat [1] '(2) nil [3])"), read_one("(1 2 3)"));
+        assert_eq!(eval_str("(seq [])"), Value::nil_ptr());
+        assert_eq!(eval_str("(seq [1])"), read_one("(1)"));
+        assert_eq!(eval_str("(vec '(1 2))"), read_one("[1 2]"));
+        assert_eq!(eval_str("(set [1 1])"), read_one("#{1}"));
+    }
+
+    // source position tests
+
+    #[test]
+    fn positions_are_read_into_meta() {
+        assert_eq!(eval_str("(get (meta '(a b)) :line)"), Value::integer_ptr(1));
+        assert_eq!(eval_str("(get (meta 'abc) :end-column)"), Value::integer_ptr(16));
+    }
+
+    #[test]
+    fn eval_error_points_at_failing_form() {
+        let err = try_eval_str("(do\n  (+ 1 2)\n  (+ 1 (unbound-thing)))").expect_err("expected an error");
+        let position = err.position().expect("expected a position");
+        assert_eq!((position.line, position.column), (3, 9));
+        let anomaly = err.to_anomaly_map();
+        assert_eq!(anomaly.inner().get(&Value::keyword_unqualified_ptr("line")), Some(Value::integer_ptr(3)));
+    }
+
+    #[test]
+    fn positions_do_not_affect_lookups() {
+        assert_eq!(eval_str("(get {'a 1} 'a)"), Value::integer_ptr(1));
+        assert_eq!(eval_str("(get {[1 2] :found} (vec '(1 2)))"), read_one(":found"));
+    }
+
+    // incomplete input tests
+
+    #[test]
+    fn input_ending_inside_a_form_is_incomplete() {
+        let env = create_env();
+        for input in ["(+ 1", "[1 [2]", "#{1", "{:a 1", "\"abc", "'", "`(a ~", "#_", "(a ; comment", "(a\n  (b"] {
+            let result = read(env.clone(), input);
+            assert!(matches!(result, Err(ReadError::Incomplete(_))), "expected {:?} to be incomplete, got: {:?}", input, result);
+        }
+    }
+
+    #[test]
+    fn erroneous_input_is_not_incomplete() {
+        let env = create_env();
+        for input in [")", "(a]", "{:a 1 :b}"] {
+            let result = read(env.clone(), input);
+            assert!(matches!(result, Err(ReadError::Anomaly(_))), "expected {:?} to be erroneous, got: {:?}", input, result);
+        }
+    }
+
+    #[test]
+    fn incomplete_input_reads_once_completed() {
+        let env = create_env();
+        let mut pending = PendingInput::new_empty();
+        pending.push("(+ 1 ; one\n");
+        assert!(read(env.clone(), pending.accumulated_input()).unwrap_err().is_incomplete());
+        pending.push("\"(\" 2) :next\n");
+        let (rest_input, value) = read(env, pending.accumulated_input()).expect("failed to read");
+        assert!(value.expect("no value read").is_list());
+        assert_eq!(rest_input, " :next\n");
+    }
+
+    // reader error tests
+
+    fn read_error(input: &str) -> ReadError {
+        read(create_env(), input).expect_err("expected a read error")
+    }
+
+    fn anomaly_get(anomaly: &read2::AnomalyMap, name: &str) -> Option<PtrValue> {
+        anomaly.inner().get(&Value::keyword_unqualified_ptr(name))
+    }
+
+    #[test]
+    fn unclosed_list_error_points_at_opening_delimiter() {
+        let anomaly = read_error("[1\n (+ 2 3").to_anomaly_map();
+        assert_eq!(anomaly.get_category(), KeywordUnqualified::new("incomplete-input"));
+        assert_eq!(anomaly.get_message(), "unclosed (");
+        assert_eq!(anomaly_get(&anomaly, "line"), Some(Value::integer_ptr(2)));
+        assert_eq!(anomaly_get(&anomaly, "column"), Some(Value::integer_ptr(2)));
+        assert_eq!(anomaly_get(&anomaly, "expected"), Some(Value::string_ptr(")".to_owned())));
+        assert_eq!(anomaly_get(&anomaly, "excerpt"), Some(Value::string_ptr(" (+ 2 3\n ^".to_owned())));
+    }
+
+    #[test]
+    fn unmatched_delimiter_error_names_expected_delimiter() {
+        let err = read_error("(let [a 1}\n  a)");
+        assert!(!err.is_incomplete());
+        assert_eq!(err.to_string(), "1:10: unmatched delimiter }, expected ]\n(let [a 1}\n         ^");
+        assert_eq!(read_error("  }").to_string(), "1:3: unmatched delimiter }\n  }\n  ^");
+    }
+
+    #[test]
+    fn odd_map_entries_error_underlines_map() {
+        let err = read_error("{:a 1 :b} :rest");
+        assert_eq!(err.to_string(), "1:1: map literal must contain an even number of forms\n{:a 1 :b} :rest\n^^^^^^^^^");
+    }
+
+    #[test]
+    fn reader_error_carries_file() {
+        let env = create_env();
+        let reader = read2::Reader::with_source("(ok)\n(oops]", Some("forms.clj".to_owned()));
+        let (remaining, _) = reader.try_read(env.clone(), "(ok)\n(oops]").expect("failed to read");
+        let err = reader.try_read(env, remaining).expect_err("expected a read error");
+        assert!(err.to_string().starts_with("forms.clj:2:6: unmatched delimiter ], expected )"));
+    }
+
+    #[test]
+    fn odd_number_of_map_forms_is_read_error() {
+        read_error("{:a 1 :b}");
+    }
+
+    // character tests
+
+    #[test]
+    fn character_conversions() {
+        assert_eq!(eval_str(r"(int \A)"), Value::integer_ptr(65));
+        assert_eq!(eval_str("(char 97)"), Value::char_ptr('a'));
+        assert_eq!(eval_str(r"(char \a)"), Value::char_ptr('a'));
+        assert_eq!(eval_str(r#"(str \a "b" \c)"#), Value::string_ptr("abc".into()));
+        assert_eq!(eval_str(r#"(first "xyz")"#), Value::char_ptr('x'));
+        assert_eq!(eval_str(r#"(last "xyz")"#), Value::char_ptr('z'));
+        assert_eq!(eval_str(r#"(seq "ab")"#).to_string(), r"(\a \b)");
+    }
+
+    // metadata and var-quote reader tests
+
+    fn meta_get(value: &PtrValue, key: PtrValue) -> Option<PtrValue> {
+        value::optics::preview_meta(value).and_then(|meta| meta.get(&key))
+    }
+
+    #[test]
+    fn meta_reader_syntax() {
+        let form = read_one("^{:doc \"d\" :n 1} [1 2]");
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("doc")), Some(Value::string_ptr("d".into())));
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("n")), Some(Value::integer_ptr(1)));
+        assert_eq!(form, read_one("[1 2]"));
+
+        let form = read_one("^:private x");
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("private")), Some(Value::boolean_ptr(true)));
+        assert_eq!(form, Value::symbol_unqualified_ptr("x"));
+
+        let form = read_one("^String x");
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("tag")), Some(Value::symbol_unqualified_ptr("String")));
+        let form = read_one("^\"String\" x");
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("tag")), Some(Value::string_ptr("String".into())));
+    }
+
+    #[test]
+    fn stacked_meta_merges_leftmost_first() {
+        let form = read_one("^:a ^{:b 1 :c 1} ^{:c 2} (f)");
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("a")), Some(Value::boolean_ptr(true)));
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("b")), Some(Value::integer_ptr(1)));
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("c")), Some(Value::integer_ptr(1)));
+    }
+
+    #[test]
+    fn meta_keeps_position_of_target() {
+        let form = read2::SourcePosition::from_value(&read_one("^:a\n  [1]")).expect("expected a position");
+        assert_eq!((form.line, form.column), (2, 3));
+        let form = read_one("^{:line 7 :column 9} [1]");
+        assert_eq!(meta_get(&form, Value::keyword_unqualified_ptr("line")), Some(Value::integer_ptr(7)));
+    }
+
+    #[test]
+    fn meta_is_visible_after_eval() {
+        assert_eq!(eval_str("(get (meta '^:k [1]) :k)"), Value::boolean_ptr(true));
+        assert_eq!(eval_str("(get (meta (quote ^{:n 5} sym)) :n)"), Value::integer_ptr(5));
+    }
+
+    #[test]
+    fn invalid_meta_is_a_read_error() {
+        assert_eq!(
+            read_error("^1 x").to_anomaly_map().get_message(),
+            "metadata must be a symbol, keyword, string or map, but got: 1"
+        );
+        assert_eq!(
+            read_error("^:a 1").to_anomaly_map().get_message(),
+            "metadata can only be applied to symbols and collections, but got: 1"
+        );
+        assert!(read_error("^:a").is_incomplete());
+    }
+
+    #[test]
+    fn var_quote_reads_as_var_form() {
+        assert_eq!(read_one("#'foo"), read_one("(var foo)"));
+        assert_eq!(read_one("#'clojure.core/str"), read_one("(var clojure.core/str)"));
+        assert!(read_error("#'").is_incomplete());
+    }
+
+    #[test]
+    fn var_quote_derefs_to_the_var_value() {
+        assert!(eval_str("#'clojure.core/str").is_var());
+        assert_eq!(eval_str("(def x 1) [(deref #'x) (let [x 2] (deref #'x))]"), read_one("[1 1]"));
+        let err = try_eval_str("#'no-such-var").unwrap_err();
+        assert_eq!(err.category(), &KeywordUnqualified::new("not-found"));
+    }
+
+    // function literal tests
+
+    fn fn_literal_params(input: &str) -> Vec<String> {
+        let form = read_one(input);
+        let Value::List(list, _) = form.as_ref() else {
+            panic!("expected a fn* form, got: {form}");
+        };
+        assert_eq!(list.get_first(), Some(Value::symbol_unqualified_ptr("fn*")));
+        coll_elements("test", &list.get_nth_or_panic(1)).iter().map(|param| param.to_string()).collect()
+    }
+
+    #[test]
+    fn fn_literal_arity_comes_from_highest_arg() {
+        assert!(fn_literal_params("#(f)").is_empty());
+        let params = fn_literal_params("#(f %)");
+        assert_eq!(params.len(), 1);
+        assert!(params[0].starts_with("p1__") && params[0].ends_with('#'));
+        assert_eq!(fn_literal_params("#(f %3 %1)").len(), 3);
+        let params = fn_literal_params("#(f % %&)");
+        assert_eq!(params.len(), 3);
+        assert_eq!(params[1], "&");
+        assert!(params[2].starts_with("rest__"));
+        assert_eq!(fn_literal_params("#(apply f %&)")[0], "&");
+    }
+
+    #[test]
+    fn fn_literal_percent_is_first_arg() {
+        let form = read_one("#(vector % %1)");
+        let Value::List(list, _) = form.as_ref() else { unreachable!() };
+        let body = list.get_nth_or_panic(2);
+        let body = coll_elements("test", &body);
+        assert_eq!(body[1], body[2]);
+        assert_eq!(fn_literal_params("#(vector % %1)").len(), 1);
+    }
+
+    #[test]
+    fn fn_literals_evaluate() {
+        assert_eq!(eval_str("(map #(+ % 1) [1 2 3])"), read_one("[2 3 4]"));
+        assert_eq!(eval_str("(#(list %2 %1) 1 2)"), read_one("(2 1)"));
+        assert_eq!(eval_str("(#(list %2) 1 2)"), read_one("(2)"));
+        assert_eq!(eval_str("(#(list % %&) 1 2 3)"), read_one("(1 (2 3))"));
+        assert_eq!(eval_str("(#(str \"x\"))"), Value::string_ptr("x".into()));
+        assert_eq!(eval_str("(let* [% 5] %)"), Value::integer_ptr(5));
+    }
+
+    #[test]
+    fn fn_literal_errors() {
+        let err = read_error("#(map #(+ % 1) %)");
+        assert_eq!(err.to_string(), "1:7: nested #()s are not allowed\n#(map #(+ % 1) %)\n      ^^");
+        assert_eq!(
+            read_error("#(f %x)").to_anomaly_map().get_message(),
+            "arg literal must be %, %& or %n with n a positive integer, but got: %x"
+        );
+        assert!(read_error("#(f %0)").to_anomaly_map().get_message().starts_with("arg literal must be"));
+        assert!(read_error("#(f %").is_incomplete());
+        // the reader is usable for another literal after a failed one
+        let env = create_env();
+        let reader = read2::Reader::new();
+        assert!(reader.try_read(env.clone(), "#(f #(g))").is_err());
+        assert!(reader.try_read(env, "#(f %)").is_ok());
+    }
+
+    // regex tests
+
+    #[test]
+    fn regex_literals() {
+        let regex = read_one(r#"#"a+b""#);
+        let regex = regex.try_get_handle_ref::<RegexHandle>().expect("expected a regex");
+        assert_eq!(regex.as_str(), "a+b");
+        let regex = read_one(r#"#"\d+\.\"x\"""#);
+        assert_eq!(regex.try_get_handle_ref::<RegexHandle>().unwrap().as_str(), r#"\d+\.\"x\""#);
+        assert!(read_error(r#"#"abc"#).is_incomplete());
+        assert!(read_error(r#"#"abc\"#).is_incomplete());
+        assert_eq!(
+            read_error(r#"#"(a""#).to_anomaly_map().get_message(),
+            "invalid regex: unclosed group"
+        );
+    }
+
+    #[test]
+    fn regexes_print_as_literals() {
+        for input in [r#"#"a+b""#, r#"#"\d+\.\"x\"""#, r#"#"[\\]""#] {
+            let regex = read_one(input);
+            assert_eq!(regex.to_string(), input);
+            let reread = read_one(&regex.to_string());
+            assert_eq!(
+                reread.try_get_handle_ref::<RegexHandle>().unwrap().as_str(),
+                regex.try_get_handle_ref::<RegexHandle>().unwrap().as_str()
+            );
+        }
+        assert_eq!(eval_str(r#"(re-pattern "a\"b")"#).to_string(), r#"#"a\"b""#);
+        assert_eq!(eval_str(r#"(str #"x*")"#), Value::string_ptr("x*".into()));
+    }
+
+    #[test]
+    fn regex_builtins() {
+        for (input, expected) in [
+            (r#"(re-find #"\d+" "ab123cd45")"#, r#""123""#),
+            (r#"(re-find #"(\w)(\d)?" "ab")"#, r#"["a" "a" nil]"#),
+            (r#"(re-find #"z" "ab")"#, "nil"),
+            (r#"(re-matches #"\d+" "123")"#, r#""123""#),
+            (r#"(re-matches #"\d+" "123x")"#, "nil"),
+            (r#"(re-matches #"a|ab" "ab")"#, r#""ab""#),
+            (r#"(re-matches #"(\w+)@(\w+)" "me@host")"#, r#"["me@host" "me" "host"]"#),
+            (r#"(re-seq #"\d" "a1b2c3")"#, r#"("1" "2" "3")"#),
+            (r#"(re-seq #"x*" "ab")"#, r#"("" "" "")"#),
+            (r#"(re-seq #"z" "ab")"#, "nil"),
+            (r#"(re-find (re-pattern "b+") "abbb")"#, r#""bbb""#),
+        ] {
+            assert_eq!(eval_str(input), read_one(expected), "evaluating {input}");
+        }
+    }
+
+    #[test]
+    fn regex_matchers() {
+        let env = create_env();
+        read_eval(env.clone(), r#"(def m (re-matcher #"(\d)(\d)" "12 34 5"))"#);
+        assert_eq!(read_eval(env.clone(), "(re-find m)"), read_one(r#"["12" "1" "2"]"#));
+        assert_eq!(read_eval(env.clone(), "(re-groups m)"), read_one(r#"["12" "1" "2"]"#));
+        assert_eq!(read_eval(env.clone(), "(re-find m)"), read_one(r#"["34" "3" "4"]"#));
+        assert_eq!(read_eval(env.clone(), "(re-find m)"), Value::nil_ptr());
+        assert_eq!(read_eval(env, "(re-find m)"), Value::nil_ptr());
+    }
+
+    #[test]
+    fn re_pattern_returns_regexes_as_is() {
+        let env = create_env();
+        read_eval(env.clone(), r#"(def r #"a")"#);
+        assert_eq!(read_eval(env.clone(), "(re-pattern r)"), read_eval(env, "r"));
+    }
+
+    // tagged literal tests
+
+    #[test]
+    fn inst_and_uuid_literals() {
+        let inst = read_one(r#"#inst "2020-01-01T01:00:00+01:00""#);
+        assert_eq!(inst.to_string(), r#"#inst "2020-01-01T00:00:00.000-00:00""#);
+        assert_eq!(read_one(&inst.to_string()), inst);
+        assert_eq!(inst, read_one(r#"#inst "2019-12-31T23:00:00-01:00""#));
+        let uuid = read_one(r#"#uuid "F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6""#);
+        assert_eq!(uuid.to_string(), r#"#uuid "f81d4fae-7dec-11d0-a765-00a0c91e6bf6""#);
+        assert_eq!(read_one(&uuid.to_string()), uuid);
+        let env = create_env();
+        assert_eq!(read_eval(env.clone(), r#"(inst? #inst "2020")"#), Value::boolean_ptr(true));
+        assert_eq!(
+            read_eval(env.clone(), r#"(uuid? #uuid "f81d4fae-7dec-11d0-a765-00a0c91e6bf6")"#),
+            Value::boolean_ptr(true)
+        );
+        assert_eq!(read_eval(env, r#"(inst? "2020")"#), Value::boolean_ptr(false));
+    }
+
+    #[test]
+    fn invalid_tagged_literals_are_read_errors() {
+        assert_eq!(
+            read_error(r#"#inst "2020-13-01""#).to_anomaly_map().get_message(),
+            "invalid timestamp: 2020-13-01"
+        );
+        assert_eq!(
+            read_error(r#"#uuid 1"#).to_anomaly_map().get_message(),
+            "#uuid requires a string, but got: 1"
+        );
+        assert_eq!(
+            read_error("#foo [1 2]").to_anomaly_map().get_message(),
+            "no reader function for tag foo"
+        );
+    }
+
+    #[test]
+    fn unknown_tags_can_be_read_as_tagged_literals() {
+        let reader = read2::Reader::new().with_unknown_tags(read2::UnknownTags::TaggedLiteral);
+        let value = reader.try_read(create_env(), "#foo [1 2]").unwrap().1.unwrap();
+        assert_eq!(value.to_string(), "#foo [1 2]");
+        assert_eq!(eval_str("(tagged-literal 'foo [1 2])"), value);
+        assert_eq!(eval_str("(tagged-literal? (tagged-literal 'foo 1))"), Value::boolean_ptr(true));
+    }
+
+    #[test]
+    fn readers_can_register_data_readers() {
+        let reader = read2::Reader::new().with_data_reader(Symbol::new_unqualified("double"), |form| {
+            value::optics::preview_integer(form.as_ref())
+                .map(|n| Value::integer_ptr(n * 2))
+                .ok_or_else(|| format!("#double requires an integer, but got: {form}"))
+        });
+        let value = reader.try_read(create_env(), "#double 21").unwrap().1.unwrap();
+        assert_eq!(value, Value::integer_ptr(42));
+        assert!(reader.try_read(create_env(), "#double :x").is_err());
+    }
+
+    #[test]
+    fn data_readers_var_is_used_by_the_reader() {
+        let env = create_env();
+        read_eval(
+            env.clone(),
+            "(intern 'clojure.core '*data-readers* {'my/tag (fn* [x] (list :tagged x))})",
+        );
+        assert_eq!(read_eval(env.clone(), "'#my/tag 1"), read_one("(:tagged 1)"));
+        // bound readers take the place of the built-in ones
+        read_eval(env.clone(), "(intern 'clojure.core '*data-readers* {'inst (fn* [x] x)})");
+        assert_eq!(read_eval(env, r#"#inst "2020""#), Value::string_ptr("2020".into()));
+    }
+
+    // streaming read tests
+
+    #[test]
+    fn read_reads_forms_one_at_a_time() {
+        let env = create_env();
+        read_eval(env.clone(), "(def r (string-reader \"(a\\n b) ; comment\\n[1\\n2] #_ skipped\\n\"))");
+        assert_eq!(read_eval(env.clone(), "(read r)"), read_one("(a b)"));
+        assert_eq!(read_eval(env.clone(), "(read r)"), read_one("[1 2]"));
+        assert_eq!(read_eval(env.clone(), "(read {:eof :done} r)"), read_one(":done"));
+        assert_eq!(read_eval(env.clone(), "(read r false :end)"), read_one(":end"));
+        assert_eq!(read_eval(env, "(read {:eof nil} r)"), Value::nil_ptr());
+    }
+
+    #[test]
+    fn read_and_read_line_share_star_in() {
+        let env = create_env();
+        assert_eq!(
+            read_eval(
+                env,
+                "(binding [*in* (string-reader \"(a\\n b) c tail\\r\\nnext\\n\")] \
+                 (list (read) (read) (read-line) (read-line) (read-line)))"
+            ),
+            read_one(r#"((a b) c " tail" "next" nil)"#)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "clojure.core/read: EOF while reading")]
+    fn read_at_end_of_input_without_eof_option() {
+        eval_str("(read (string-reader \"  \"))");
+    }
+
+    #[test]
+    #[should_panic(expected = "clojure.core/read: EOF while reading, unclosed (")]
+    fn read_at_end_of_input_partway_through_a_form() {
+        eval_str("(read {:eof :done} (string-reader \"(a b\"))");
+    }
+
+    #[test]
+    fn read_string_reads_the_first_form() {
+        assert_eq!(eval_str(r#"(read-string "(+ 1 2) 3")"#), read_one("(+ 1 2)"));
+        assert_eq!(eval_str(r#"(read-string {:eof :none} " ; nothing")"#), read_one(":none"));
+        assert_eq!(eval_str(r##"(read-string "#inst \"2020\"")"##), read_one(r#"#inst "2020""#));
+    }
+
+    #[test]
+    #[should_panic(expected = "clojure.core/read-string: unmatched delimiter ]")]
+    fn read_string_of_unreadable_input() {
+        eval_str(r#"(read-string "(a]")"#);
+    }
 }